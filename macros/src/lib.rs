@@ -0,0 +1,85 @@
+//! Procedural macros that compute crc32 checksums at macro-expansion time.
+//!
+//! The `const fn`s in `const-crc32-nostd` run into the compiler's const evaluation limit on
+//! large inputs. These macros compute the checksum while expanding, using the same
+//! `const_crc32_nostd::crc32`, and emit it as a `u32` literal, so there is no limit on the
+//! input size.
+//!
+//! # Examples
+//!
+//! ```
+//! use const_crc32_nostd_macros::crc32;
+//!
+//! const CKSUM: u32 = crc32!("The quick brown fox jumps over the lazy dog");
+//! assert_eq!(CKSUM, 0x414fa339_u32);
+//!
+//! const BYTES_CKSUM: u32 = crc32!(b"The quick brown fox jumps over the lazy dog");
+//! assert_eq!(BYTES_CKSUM, CKSUM);
+//! ```
+
+use proc_macro::{Literal, TokenStream, TokenTree};
+use std::path::PathBuf;
+
+use syn::{parse_macro_input, Lit, LitStr};
+
+/// Expands to the crc32 checksum of a string or byte string literal, as a `u32` literal.
+///
+/// `crc32!("abc")` is equal to `const_crc32_nostd::crc32("abc".as_bytes())` and
+/// `crc32!(b"abc")` to `const_crc32_nostd::crc32(b"abc")`.
+#[proc_macro]
+pub fn crc32(input: TokenStream) -> TokenStream {
+    let lit = parse_macro_input!(input as Lit);
+
+    let cksum = match &lit {
+        Lit::Str(s) => const_crc32_nostd::crc32(s.value().as_bytes()),
+        Lit::ByteStr(b) => const_crc32_nostd::crc32(&b.value()),
+        _ => {
+            return syn::Error::new(lit.span(), "expected a string or byte string literal")
+                .to_compile_error()
+                .into()
+        }
+    };
+
+    TokenTree::Literal(Literal::u32_suffixed(cksum)).into()
+}
+
+/// Expands to the crc32 checksum of a file's contents, as a `u32` literal.
+///
+/// The path is relative to the `CARGO_MANIFEST_DIR` of the crate being compiled, unlike
+/// `include_bytes!`, which is relative to the current file. The file is also passed to
+/// `include_bytes!` in an unused `const`, so that the compiler rebuilds when it changes.
+#[proc_macro]
+pub fn crc32_file(input: TokenStream) -> TokenStream {
+    let lit = parse_macro_input!(input as LitStr);
+
+    let mut path = match std::env::var_os("CARGO_MANIFEST_DIR") {
+        Some(dir) => PathBuf::from(dir),
+        None => PathBuf::new(),
+    };
+    path.push(lit.value());
+
+    let bytes = match std::fs::read(&path) {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            let msg = format!("couldn't read {}: {}", path.display(), e);
+            return syn::Error::new(lit.span(), msg).to_compile_error().into();
+        }
+    };
+    let cksum = const_crc32_nostd::crc32(&bytes);
+
+    let path = match path.to_str() {
+        Some(path) => path,
+        None => {
+            return syn::Error::new(lit.span(), "path is not valid UTF-8")
+                .to_compile_error()
+                .into()
+        }
+    };
+
+    format!(
+        "{{ const _: &[u8] = include_bytes!({:?}); {}u32 }}",
+        path, cksum
+    )
+    .parse()
+    .unwrap()
+}
@@ -0,0 +1,34 @@
+use const_crc32_nostd_macros::{crc32, crc32_file};
+
+const FOX: &str = "The quick brown fox jumps over the lazy dog";
+
+#[test]
+fn string_literal_matches_const_fn() {
+    const CKSUM: u32 = crc32!("The quick brown fox jumps over the lazy dog");
+    assert_eq!(CKSUM, const_crc32_nostd::crc32(FOX.as_bytes()));
+    assert_eq!(crc32!(""), const_crc32_nostd::crc32(&[]));
+    assert_eq!(
+        crc32!("h\u{e9}llo\n"),
+        const_crc32_nostd::crc32("h\u{e9}llo\n".as_bytes())
+    );
+}
+
+#[test]
+fn byte_string_literal_matches_const_fn() {
+    const CKSUM: u32 = crc32!(b"The quick brown fox jumps over the lazy dog");
+    assert_eq!(CKSUM, const_crc32_nostd::crc32(FOX.as_bytes()));
+    assert_eq!(
+        crc32!(b"\x00\xff\n"),
+        const_crc32_nostd::crc32(b"\x00\xff\n")
+    );
+}
+
+#[test]
+fn file_matches_const_fn() {
+    const CKSUM: u32 = crc32_file!("tests/data/fox.txt");
+    assert_eq!(
+        CKSUM,
+        const_crc32_nostd::crc32(include_bytes!("data/fox.txt"))
+    );
+    assert_eq!(CKSUM, const_crc32_nostd::crc32(FOX.as_bytes()));
+}
@@ -0,0 +1,441 @@
+//! Async counterparts of the [`io`](crate::io) adaptors.
+//!
+//! The same types implement the [`futures-io`](https://docs.rs/futures-io) traits when the
+//! `futures-io` feature is enabled and the [`tokio`](https://docs.rs/tokio) traits when the
+//! `tokio` feature is enabled. The checksum is updated with the bytes of each completed poll.
+
+use core::pin::Pin;
+use core::task::{Context, Poll};
+use std::io;
+
+use pin_project_lite::pin_project;
+
+use crate::{ChecksumMismatch, Crc32};
+
+pin_project! {
+    /// An async writer computing the crc32 of everything written to the inner writer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "futures-io")]
+    /// # futures::executor::block_on(async {
+    /// use const_crc32_nostd::{async_io::Crc32Writer, crc32};
+    /// use futures::io::AsyncWriteExt;
+    ///
+    /// let mut writer = Crc32Writer::new(Vec::new());
+    /// writer.write_all(b"The quick brown fox jumps over the lazy dog").await.unwrap();
+    ///
+    /// let (buf, cksum) = writer.into_parts();
+    /// assert_eq!(cksum, crc32(&buf));
+    /// # });
+    /// ```
+    #[derive(Debug)]
+    pub struct Crc32Writer<W> {
+        #[pin]
+        inner: W,
+        crc: Crc32,
+    }
+}
+
+impl<W> Crc32Writer<W> {
+    /// Wrap `inner`, starting from the checksum of an empty input.
+    pub fn new(inner: W) -> Self {
+        Self::with_crc(inner, Crc32::new())
+    }
+
+    /// Wrap `inner`, continuing from the checksum state `crc`.
+    pub fn with_crc(inner: W, crc: Crc32) -> Self {
+        Self { inner, crc }
+    }
+
+    /// The crc32 of everything written so far.
+    pub fn checksum(&self) -> u32 {
+        self.crc.finalize()
+    }
+
+    /// A reference to the inner writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// A mutable reference to the inner writer. Bytes written through it are not checksummed.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// A pinned mutable reference to the inner writer. Bytes written through it are not
+    /// checksummed.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut W> {
+        self.project().inner
+    }
+
+    /// Unwrap the inner writer, discarding the checksum.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Unwrap the inner writer together with the checksum of everything written.
+    pub fn into_parts(self) -> (W, u32) {
+        let cksum = self.checksum();
+        (self.inner, cksum)
+    }
+
+    /// checksums the bytes accepted by a completed `poll_write`
+    fn poll_write_with(
+        self: Pin<&mut Self>,
+        buf: &[u8],
+        poll: impl FnOnce(Pin<&mut W>, &[u8]) -> Poll<io::Result<usize>>,
+    ) -> Poll<io::Result<usize>> {
+        let this = self.project();
+        let result = poll(this.inner, buf);
+        if let Poll::Ready(Ok(n)) = result {
+            *this.crc = this.crc.update(&buf[..n]);
+        }
+        result
+    }
+}
+
+pin_project! {
+    /// An async reader computing the crc32 of everything read from the inner reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "futures-io")]
+    /// # futures::executor::block_on(async {
+    /// use const_crc32_nostd::{async_io::Crc32Reader, crc32};
+    /// use futures::io::AsyncReadExt;
+    ///
+    /// const BYTES: &[u8] = "The quick brown fox jumps over the lazy dog".as_bytes();
+    ///
+    /// let mut reader = Crc32Reader::new(BYTES);
+    /// let mut buf = Vec::new();
+    /// reader.read_to_end(&mut buf).await.unwrap();
+    ///
+    /// assert_eq!(reader.checksum(), crc32(BYTES));
+    /// # });
+    /// ```
+    #[derive(Debug)]
+    pub struct Crc32Reader<R> {
+        #[pin]
+        inner: R,
+        crc: Crc32,
+    }
+}
+
+impl<R> Crc32Reader<R> {
+    /// Wrap `inner`, starting from the checksum of an empty input.
+    pub fn new(inner: R) -> Self {
+        Self::with_crc(inner, Crc32::new())
+    }
+
+    /// Wrap `inner`, continuing from the checksum state `crc`.
+    pub fn with_crc(inner: R, crc: Crc32) -> Self {
+        Self { inner, crc }
+    }
+
+    /// The crc32 of everything read so far.
+    pub fn checksum(&self) -> u32 {
+        self.crc.finalize()
+    }
+
+    /// A reference to the inner reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// A mutable reference to the inner reader. Bytes read through it are not checksummed.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// A pinned mutable reference to the inner reader. Bytes read through it are not
+    /// checksummed.
+    pub fn get_pin_mut(self: Pin<&mut Self>) -> Pin<&mut R> {
+        self.project().inner
+    }
+
+    /// Unwrap the inner reader, discarding the checksum.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+pin_project! {
+    /// An async reader that checks the crc32 of everything read against an expected value.
+    ///
+    /// Once the inner reader reaches end of file, a read fails with an error of kind
+    /// [`io::ErrorKind::InvalidData`], wrapping a [`ChecksumMismatch`], if the checksum does
+    /// not match. As with
+    /// [`io::Crc32VerifyingReader`](crate::io::Crc32VerifyingReader), data is passed through as
+    /// it arrives and must not be acted on until end of file was reached without error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #[cfg(feature = "futures-io")]
+    /// # futures::executor::block_on(async {
+    /// use const_crc32_nostd::async_io::Crc32VerifyingReader;
+    /// use futures::io::AsyncReadExt;
+    ///
+    /// const BYTES: &[u8] = "The quick brown fox jumps over the lazy dog".as_bytes();
+    ///
+    /// let mut buf = Vec::new();
+    /// let mut reader = Crc32VerifyingReader::new(BYTES, 0xbaaaaaad);
+    /// let err = reader.read_to_end(&mut buf).await.unwrap_err();
+    /// assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    /// # });
+    /// ```
+    #[derive(Debug)]
+    pub struct Crc32VerifyingReader<R> {
+        #[pin]
+        inner: Crc32Reader<R>,
+        expected: u32,
+    }
+}
+
+impl<R> Crc32VerifyingReader<R> {
+    /// Wrap `inner`, expecting the crc32 of its contents to be `expected`.
+    pub fn new(inner: R, expected: u32) -> Self {
+        Self {
+            inner: Crc32Reader::new(inner),
+            expected,
+        }
+    }
+
+    /// The crc32 of everything read so far.
+    pub fn checksum(&self) -> u32 {
+        self.inner.checksum()
+    }
+
+    /// The checksum expected at end of file.
+    pub fn expected(&self) -> u32 {
+        self.expected
+    }
+
+    /// Unwrap the inner reader.
+    pub fn into_inner(self) -> R {
+        self.inner.into_inner()
+    }
+
+    /// the error returned at end of file if the checksum does not match
+    fn check_at_eof(&self) -> io::Result<()> {
+        if self.checksum() == self.expected {
+            return Ok(());
+        }
+        Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            ChecksumMismatch {
+                expected: self.expected,
+                actual: self.checksum(),
+            },
+        ))
+    }
+}
+
+#[cfg(feature = "futures-io")]
+mod futures_impls {
+    use super::*;
+    use futures_io::{AsyncRead, AsyncWrite};
+
+    impl<W: AsyncWrite> AsyncWrite for Crc32Writer<W> {
+        fn poll_write(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            self.poll_write_with(buf, |inner, buf| inner.poll_write(cx, buf))
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            self.project().inner.poll_flush(cx)
+        }
+
+        fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            self.project().inner.poll_close(cx)
+        }
+    }
+
+    impl<R: AsyncRead> AsyncRead for Crc32Reader<R> {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<io::Result<usize>> {
+            let this = self.project();
+            let result = this.inner.poll_read(cx, buf);
+            if let Poll::Ready(Ok(n)) = result {
+                *this.crc = this.crc.update(&buf[..n]);
+            }
+            result
+        }
+    }
+
+    impl<R: AsyncRead> AsyncRead for Crc32VerifyingReader<R> {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<io::Result<usize>> {
+            let n = match self.as_mut().project().inner.poll_read(cx, buf) {
+                Poll::Ready(Ok(n)) => n,
+                other => return other,
+            };
+            if n == 0 && !buf.is_empty() {
+                self.check_at_eof()?;
+            }
+            Poll::Ready(Ok(n))
+        }
+    }
+}
+
+#[cfg(feature = "tokio")]
+mod tokio_impls {
+    use super::*;
+    use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+    impl<W: AsyncWrite> AsyncWrite for Crc32Writer<W> {
+        fn poll_write(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            self.poll_write_with(buf, |inner, buf| inner.poll_write(cx, buf))
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            self.project().inner.poll_flush(cx)
+        }
+
+        fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            self.project().inner.poll_shutdown(cx)
+        }
+    }
+
+    impl<R: AsyncRead> AsyncRead for Crc32Reader<R> {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<io::Result<()>> {
+            let this = self.project();
+            let before = buf.filled().len();
+            let result = this.inner.poll_read(cx, buf);
+            if let Poll::Ready(Ok(())) = result {
+                *this.crc = this.crc.update(&buf.filled()[before..]);
+            }
+            result
+        }
+    }
+
+    impl<R: AsyncRead> AsyncRead for Crc32VerifyingReader<R> {
+        fn poll_read(
+            mut self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut ReadBuf<'_>,
+        ) -> Poll<io::Result<()>> {
+            let before = buf.filled().len();
+            let result = self.as_mut().project().inner.poll_read(cx, buf);
+            if let Poll::Ready(Ok(())) = result {
+                if buf.filled().len() == before && buf.remaining() > 0 {
+                    self.check_at_eof()?;
+                }
+            }
+            result
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crc32;
+    use futures::executor::block_on;
+    use rand::prelude::*;
+    use std::vec::Vec;
+
+    fn random_bytes() -> Vec<u8> {
+        let mut buf = std::vec![0u8; 4096];
+        thread_rng().fill(&mut buf[..]);
+        buf
+    }
+
+    #[cfg(feature = "futures-io")]
+    #[test]
+    fn futures_reader_and_writer() {
+        use futures::io::{AsyncReadExt, AsyncWriteExt};
+
+        let buf = random_bytes();
+        block_on(async {
+            let mut writer = Crc32Writer::new(Vec::new());
+            for chunk in buf.chunks(100) {
+                writer.write_all(chunk).await.unwrap();
+            }
+            writer.close().await.unwrap();
+            assert_eq!(writer.checksum(), crc32(&buf));
+
+            let mut reader = Crc32Reader::new(&buf[..]);
+            let mut out = Vec::new();
+            reader.read_to_end(&mut out).await.unwrap();
+            assert_eq!(out, buf);
+            assert_eq!(reader.checksum(), crc32(&buf));
+        });
+    }
+
+    #[cfg(feature = "futures-io")]
+    #[test]
+    fn futures_verifying_reader() {
+        use futures::io::AsyncReadExt;
+
+        let buf = random_bytes();
+        block_on(async {
+            let mut out = Vec::new();
+            let mut reader = Crc32VerifyingReader::new(&buf[..], crc32(&buf));
+            reader.read_to_end(&mut out).await.unwrap();
+
+            let mut reader = Crc32VerifyingReader::new(&buf[1..], crc32(&buf));
+            let err = reader.read_to_end(&mut out).await.unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        });
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn tokio_reader_and_writer() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let buf = random_bytes();
+        block_on(async {
+            let mut writer = Crc32Writer::new(Vec::new());
+            for chunk in buf.chunks(100) {
+                writer.write_all(chunk).await.unwrap();
+            }
+            writer.shutdown().await.unwrap();
+            assert_eq!(writer.checksum(), crc32(&buf));
+
+            let mut reader = Crc32Reader::new(&buf[..]);
+            let mut out = Vec::new();
+            reader.read_to_end(&mut out).await.unwrap();
+            assert_eq!(out, buf);
+            assert_eq!(reader.checksum(), crc32(&buf));
+        });
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn tokio_verifying_reader() {
+        use tokio::io::AsyncReadExt;
+
+        let buf = random_bytes();
+        block_on(async {
+            let mut out = Vec::new();
+            let mut reader = Crc32VerifyingReader::new(&buf[..], crc32(&buf));
+            reader.read_to_end(&mut out).await.unwrap();
+
+            let mut reader = Crc32VerifyingReader::new(&buf[1..], crc32(&buf));
+            let err = reader.read_to_end(&mut out).await.unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        });
+    }
+}
@@ -0,0 +1,382 @@
+//! Parameters of well-known CRC algorithms.
+//!
+//! Names and values are taken from the
+//! [catalogue of parametrised CRC algorithms](https://reveng.sourceforge.io/crc-catalogue/).
+
+use crate::Algorithm;
+
+/// CRC-32/ISO-HDLC, the CRC-32 used by zlib, gzip, PNG and Ethernet, computed by
+/// [`crc32`](crate::crc32).
+pub const CRC_32_ISO_HDLC: Algorithm<u32> = Algorithm {
+    width: 32,
+    poly: 0x04c11db7,
+    init: 0xffffffff,
+    refin: true,
+    refout: true,
+    xorout: 0xffffffff,
+    check: 0xcbf43926,
+    residue: 0xdebb20e3,
+};
+
+/// CRC-32/ISCSI (CRC-32C, Castagnoli), used by iSCSI, ext4, Btrfs and SCTP, computed by
+/// [`crc32c`](crate::crc32c).
+pub const CRC_32_ISCSI: Algorithm<u32> = Algorithm {
+    width: 32,
+    poly: 0x1edc6f41,
+    init: 0xffffffff,
+    refin: true,
+    refout: true,
+    xorout: 0xffffffff,
+    check: 0xe3069283,
+    residue: 0xb798b438,
+};
+
+/// CRC-32/BZIP2, the unreflected CRC-32 used by bzip2 and AAL5, computed by
+/// [`crc32_bzip2`](crate::crc32_bzip2).
+pub const CRC_32_BZIP2: Algorithm<u32> = Algorithm {
+    width: 32,
+    poly: 0x04c11db7,
+    init: 0xffffffff,
+    refin: false,
+    refout: false,
+    xorout: 0xffffffff,
+    check: 0xfc891918,
+    residue: 0xc704dd7b,
+};
+
+/// CRC-32/MPEG-2, used by MPEG transport streams, computed by [`crc32_mpeg2`](crate::crc32_mpeg2).
+pub const CRC_32_MPEG_2: Algorithm<u32> = Algorithm {
+    width: 32,
+    poly: 0x04c11db7,
+    init: 0xffffffff,
+    refin: false,
+    refout: false,
+    xorout: 0x00000000,
+    check: 0x0376e6e7,
+    residue: 0x00000000,
+};
+
+/// CRC-32/CKSUM, the CRC underlying POSIX `cksum`. Note that `cksum` also feeds the message
+/// length through the register, see [`crc32_cksum`](crate::crc32_cksum).
+pub const CRC_32_CKSUM: Algorithm<u32> = Algorithm {
+    width: 32,
+    poly: 0x04c11db7,
+    init: 0x00000000,
+    refin: false,
+    refout: false,
+    xorout: 0xffffffff,
+    check: 0x765e7680,
+    residue: 0xc704dd7b,
+};
+
+/// CRC-64/XZ, used by xz and 7-Zip, computed by [`crc64_xz`](crate::crc64_xz).
+pub const CRC_64_XZ: Algorithm<u64> = Algorithm {
+    width: 64,
+    poly: 0x42f0e1eba9ea3693,
+    init: 0xffffffffffffffff,
+    refin: true,
+    refout: true,
+    xorout: 0xffffffffffffffff,
+    check: 0x995dc9bbdf1939fa,
+    residue: 0x49958c9abd7d353f,
+};
+
+/// CRC-64/ECMA-182, computed by [`crc64_ecma_182`](crate::crc64_ecma_182).
+pub const CRC_64_ECMA_182: Algorithm<u64> = Algorithm {
+    width: 64,
+    poly: 0x42f0e1eba9ea3693,
+    init: 0x0000000000000000,
+    refin: false,
+    refout: false,
+    xorout: 0x0000000000000000,
+    check: 0x6c40df5f0b497347,
+    residue: 0x0000000000000000,
+};
+
+/// CRC-64/NVME, used by NVMe end-to-end data protection, computed by
+/// [`crc64_nvme`](crate::crc64_nvme).
+pub const CRC_64_NVME: Algorithm<u64> = Algorithm {
+    width: 64,
+    poly: 0xad93d23594c93659,
+    init: 0xffffffffffffffff,
+    refin: true,
+    refout: true,
+    xorout: 0xffffffffffffffff,
+    check: 0xae8b14860a799888,
+    residue: 0xf310303b2b6f6e42,
+};
+
+/// CRC-64/GO-ISO, the ISO 3309 CRC-64 of Go's `hash/crc64`, computed by
+/// [`crc64_go_iso`](crate::crc64_go_iso).
+pub const CRC_64_GO_ISO: Algorithm<u64> = Algorithm {
+    width: 64,
+    poly: 0x000000000000001b,
+    init: 0xffffffffffffffff,
+    refin: true,
+    refout: true,
+    xorout: 0xffffffffffffffff,
+    check: 0xb90956c775a41001,
+    residue: 0x5300000000000000,
+};
+
+/// CRC-16/IBM-3740, also known as CRC-16/CCITT-FALSE and CRC-16/AUTOSAR, computed by
+/// [`crc16_ccitt_false`](crate::crc16_ccitt_false).
+pub const CRC_16_IBM_3740: Algorithm<u16> = Algorithm {
+    width: 16,
+    poly: 0x1021,
+    init: 0xffff,
+    refin: false,
+    refout: false,
+    xorout: 0x0000,
+    check: 0x29b1,
+    residue: 0x0000,
+};
+
+/// CRC-16/XMODEM, also known as CRC-16/ACORN and CRC-16/V-41-MSB, computed by
+/// [`crc16_xmodem`](crate::crc16_xmodem).
+pub const CRC_16_XMODEM: Algorithm<u16> = Algorithm {
+    width: 16,
+    poly: 0x1021,
+    init: 0x0000,
+    refin: false,
+    refout: false,
+    xorout: 0x0000,
+    check: 0x31c3,
+    residue: 0x0000,
+};
+
+/// CRC-16/KERMIT, also known as CRC-16/CCITT and CRC-16/V-41-LSB, computed by
+/// [`crc16_kermit`](crate::crc16_kermit).
+pub const CRC_16_KERMIT: Algorithm<u16> = Algorithm {
+    width: 16,
+    poly: 0x1021,
+    init: 0x0000,
+    refin: true,
+    refout: true,
+    xorout: 0x0000,
+    check: 0x2189,
+    residue: 0x0000,
+};
+
+/// CRC-16/MODBUS, used by Modbus RTU, computed by [`crc16_modbus`](crate::crc16_modbus).
+pub const CRC_16_MODBUS: Algorithm<u16> = Algorithm {
+    width: 16,
+    poly: 0x8005,
+    init: 0xffff,
+    refin: true,
+    refout: true,
+    xorout: 0x0000,
+    check: 0x4b37,
+    residue: 0x0000,
+};
+
+/// CRC-16/USB, used by USB data packets, computed by [`crc16_usb`](crate::crc16_usb).
+pub const CRC_16_USB: Algorithm<u16> = Algorithm {
+    width: 16,
+    poly: 0x8005,
+    init: 0xffff,
+    refin: true,
+    refout: true,
+    xorout: 0xffff,
+    check: 0xb4c8,
+    residue: 0xb001,
+};
+
+/// CRC-16/ARC, also known as CRC-16/IBM and CRC-16/LHA, computed by
+/// [`crc16_arc`](crate::crc16_arc).
+pub const CRC_16_ARC: Algorithm<u16> = Algorithm {
+    width: 16,
+    poly: 0x8005,
+    init: 0x0000,
+    refin: true,
+    refout: true,
+    xorout: 0x0000,
+    check: 0xbb3d,
+    residue: 0x0000,
+};
+
+/// CRC-16/IBM-SDLC, also known as CRC-16/X-25 and CRC-16/ISO-HDLC, computed by
+/// [`crc16_x25`](crate::crc16_x25).
+pub const CRC_16_IBM_SDLC: Algorithm<u16> = Algorithm {
+    width: 16,
+    poly: 0x1021,
+    init: 0xffff,
+    refin: true,
+    refout: true,
+    xorout: 0xffff,
+    check: 0x906e,
+    residue: 0xf0b8,
+};
+
+/// CRC-16/MCRF4XX, used by Microchip MCRF4xx RFID tags, computed by
+/// [`crc16_mcrf4xx`](crate::crc16_mcrf4xx).
+pub const CRC_16_MCRF4XX: Algorithm<u16> = Algorithm {
+    width: 16,
+    poly: 0x1021,
+    init: 0xffff,
+    refin: true,
+    refout: true,
+    xorout: 0x0000,
+    check: 0x6f91,
+    residue: 0x0000,
+};
+
+/// CRC-3/GSM, used by GSM control channels, computed by [`crc3_gsm`](crate::crc3_gsm).
+pub const CRC_3_GSM: Algorithm<u8> = Algorithm {
+    width: 3,
+    poly: 0x3,
+    init: 0x0,
+    refin: false,
+    refout: false,
+    xorout: 0x7,
+    check: 0x4,
+    residue: 0x2,
+};
+
+/// CRC-3/ROHC, used by robust header compression, computed by [`crc3_rohc`](crate::crc3_rohc).
+pub const CRC_3_ROHC: Algorithm<u8> = Algorithm {
+    width: 3,
+    poly: 0x3,
+    init: 0x7,
+    refin: true,
+    refout: true,
+    xorout: 0x0,
+    check: 0x6,
+    residue: 0x0,
+};
+
+/// CRC-4/G-704, also known as CRC-4/ITU, used by E1 framing, computed by
+/// [`crc4_g_704`](crate::crc4_g_704).
+pub const CRC_4_G_704: Algorithm<u8> = Algorithm {
+    width: 4,
+    poly: 0x3,
+    init: 0x0,
+    refin: true,
+    refout: true,
+    xorout: 0x0,
+    check: 0x7,
+    residue: 0x0,
+};
+
+/// CRC-5/USB, used by USB token packets, computed by [`crc5_usb`](crate::crc5_usb).
+pub const CRC_5_USB: Algorithm<u8> = Algorithm {
+    width: 5,
+    poly: 0x05,
+    init: 0x1f,
+    refin: true,
+    refout: true,
+    xorout: 0x1f,
+    check: 0x19,
+    residue: 0x06,
+};
+
+/// CRC-5/EPC-C1G2, used by EPC Gen 2 RFID tags, computed by
+/// [`crc5_epc_c1g2`](crate::crc5_epc_c1g2).
+pub const CRC_5_EPC_C1G2: Algorithm<u8> = Algorithm {
+    width: 5,
+    poly: 0x09,
+    init: 0x09,
+    refin: false,
+    refout: false,
+    xorout: 0x00,
+    check: 0x00,
+    residue: 0x00,
+};
+
+/// CRC-6/G-704, also known as CRC-6/ITU, computed by [`crc6_g_704`](crate::crc6_g_704).
+pub const CRC_6_G_704: Algorithm<u8> = Algorithm {
+    width: 6,
+    poly: 0x03,
+    init: 0x00,
+    refin: true,
+    refout: true,
+    xorout: 0x00,
+    check: 0x06,
+    residue: 0x00,
+};
+
+/// CRC-7/MMC, used by MMC and SD card commands, computed by [`crc7_mmc`](crate::crc7_mmc).
+pub const CRC_7_MMC: Algorithm<u8> = Algorithm {
+    width: 7,
+    poly: 0x09,
+    init: 0x00,
+    refin: false,
+    refout: false,
+    xorout: 0x00,
+    check: 0x75,
+    residue: 0x00,
+};
+
+/// CRC-8/SMBUS, used by SMBus packet error checking, computed by [`crc8_smbus`](crate::crc8_smbus).
+pub const CRC_8_SMBUS: Algorithm<u8> = Algorithm {
+    width: 8,
+    poly: 0x07,
+    init: 0x00,
+    refin: false,
+    refout: false,
+    xorout: 0x00,
+    check: 0xf4,
+    residue: 0x00,
+};
+
+/// CRC-8/MAXIM-DOW, also known as CRC-8/MAXIM, used by the 1-Wire bus, computed by
+/// [`crc8_maxim`](crate::crc8_maxim).
+pub const CRC_8_MAXIM_DOW: Algorithm<u8> = Algorithm {
+    width: 8,
+    poly: 0x31,
+    init: 0x00,
+    refin: true,
+    refout: true,
+    xorout: 0x00,
+    check: 0xa1,
+    residue: 0x00,
+};
+
+/// CRC-10/ATM, used by ATM OAM cells, computed by [`crc10_atm`](crate::crc10_atm).
+pub const CRC_10_ATM: Algorithm<u16> = Algorithm {
+    width: 10,
+    poly: 0x233,
+    init: 0x000,
+    refin: false,
+    refout: false,
+    xorout: 0x000,
+    check: 0x199,
+    residue: 0x000,
+};
+
+/// CRC-11/FLEXRAY, used by FlexRay frame headers, computed by
+/// [`crc11_flexray`](crate::crc11_flexray).
+pub const CRC_11_FLEXRAY: Algorithm<u16> = Algorithm {
+    width: 11,
+    poly: 0x385,
+    init: 0x01a,
+    refin: false,
+    refout: false,
+    xorout: 0x000,
+    check: 0x5a3,
+    residue: 0x000,
+};
+
+/// CRC-12/DECT, used by DECT, computed by [`crc12_dect`](crate::crc12_dect).
+pub const CRC_12_DECT: Algorithm<u16> = Algorithm {
+    width: 12,
+    poly: 0x80f,
+    init: 0x000,
+    refin: false,
+    refout: false,
+    xorout: 0x000,
+    check: 0xf5b,
+    residue: 0x000,
+};
+
+/// CRC-15/CAN, used by CAN bus frames, computed by [`crc15_can`](crate::crc15_can).
+pub const CRC_15_CAN: Algorithm<u16> = Algorithm {
+    width: 15,
+    poly: 0x4599,
+    init: 0x0000,
+    refin: false,
+    refout: false,
+    xorout: 0x0000,
+    check: 0x059e,
+    residue: 0x0000,
+};
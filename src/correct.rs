@@ -0,0 +1,286 @@
+//! Locating and correcting small errors in a frame from its crc32 syndrome.
+//!
+//! The syndrome is the crc32 of the received data xored with the received checksum. Since
+//! crc32 is linear, it depends only on the error pattern and where it sits in the frame, not
+//! on the data, so an error can be located by searching for the pattern and position whose
+//! syndrome matches. This module searches single-bit errors and, optionally, bursts of up to
+//! 16 bits, both in the data and in the checksum itself, and applies the correction only if
+//! exactly one candidate matches.
+//!
+//! Bit positions count in transmission order: bit `i` of a frame is
+//! `(frame[i / 8] >> (i % 8)) & 1`, least significant bit first, which is the order in which
+//! crc32 consumes each byte.
+
+use core::fmt;
+
+use crate::{crc32, gf2, IEEE, IEEE_POLY};
+
+/// largest burst length supported by [`Corrector::with_max_burst`]
+const MAX_BURST: u32 = 16;
+
+/// x^8 mod P(x), bit-reflected
+const X8: u32 = 1 << (31 - 8);
+
+/// Single-bit and burst error correction for crc32-protected frames.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd::crc32;
+/// use const_crc32_nostd::correct::{Correction, Corrector};
+///
+/// const CORRECTOR: Corrector = Corrector::new(64).with_max_burst(4);
+///
+/// let sent = *b"The quick brown fox jumps over the lazy dog";
+/// let cksum = crc32(&sent);
+///
+/// let mut received = sent;
+/// received[9] ^= 0b0110;
+///
+/// assert_eq!(
+///     CORRECTOR.correct(&mut received, cksum),
+///     Ok(Correction::Data { bit: 9 * 8 + 1, pattern: 0b11 }),
+/// );
+/// assert_eq!(received, sent);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Corrector {
+    max_len: usize,
+    max_burst: u32,
+}
+
+/// A successful outcome of [`Corrector::correct`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Correction {
+    /// the checksum matched, nothing was changed
+    Clean,
+    /// the bits of `pattern`, starting at bit `bit` of the frame, were flipped back
+    Data {
+        /// position of the first flipped bit
+        bit: usize,
+        /// flipped bits, bit `j` standing for bit `bit + j` of the frame
+        pattern: u32,
+    },
+    /// the data was intact and the error was in the received checksum
+    Checksum {
+        /// the checksum that was actually sent
+        corrected: u32,
+    },
+}
+
+/// Why [`Corrector::correct`] could not correct a frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Uncorrectable {
+    /// the frame is longer than the corrector's maximum frame length
+    TooLong,
+    /// no error pattern within the search limits explains the syndrome
+    NoMatch,
+    /// more than one error pattern explains the syndrome
+    Ambiguous,
+}
+
+impl fmt::Display for Uncorrectable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Uncorrectable::TooLong => "frame exceeds the maximum correctable length",
+            Uncorrectable::NoMatch => "no correctable error pattern matches the crc32 syndrome",
+            Uncorrectable::Ambiguous => "several error patterns match the crc32 syndrome",
+        })
+    }
+}
+
+impl core::error::Error for Uncorrectable {}
+
+impl Corrector {
+    /// Correct single-bit errors in frames of up to `max_len` bytes.
+    pub const fn new(max_len: usize) -> Self {
+        Self {
+            max_len,
+            max_burst: 1,
+        }
+    }
+
+    /// Also correct bursts of up to `bits` bits: error patterns whose first and last flipped
+    /// bits are at most `bits - 1` positions apart. The search tries `2^(bits - 1)` patterns
+    /// at every bit position, and longer bursts make ambiguous syndromes more likely.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bits` is zero or greater than 16.
+    pub const fn with_max_burst(self, bits: u32) -> Self {
+        assert!(bits > 0 && bits <= MAX_BURST);
+        Self {
+            max_len: self.max_len,
+            max_burst: bits,
+        }
+    }
+
+    /// Check `frame` against its received checksum `crc`, correcting it in place if the
+    /// mismatch is explained by exactly one error pattern within the limits of `self`.
+    ///
+    /// `frame` is left untouched unless `Ok(Correction::Data { .. })` is returned.
+    pub fn correct(&self, frame: &mut [u8], crc: u32) -> Result<Correction, Uncorrectable> {
+        if frame.len() > self.max_len {
+            return Err(Uncorrectable::TooLong);
+        }
+
+        let syndrome = crc32(frame) ^ crc;
+        if syndrome == 0 {
+            return Ok(Correction::Clean);
+        }
+
+        let mut found = None;
+
+        // an error in the checksum shows up in the syndrome as is
+        let shift = syndrome.trailing_zeros();
+        if (syndrome >> shift) >> (self.max_burst - 1) <= 1 {
+            found = Some(Correction::Checksum {
+                corrected: crc ^ syndrome,
+            });
+        }
+
+        // syndromes of single-bit errors at positions bit, bit + 1, ..., walking backwards
+        // from the end of the frame
+        let mut window = [0u32; MAX_BURST as usize];
+        let mut n_bits = 0usize;
+        // x^(8k) mod P(x), k being the number of bytes after the current one
+        let mut factor = 1u32 << 31;
+
+        for byte in (0..frame.len()).rev() {
+            for bit in (0..8).rev() {
+                window.copy_within(..MAX_BURST as usize - 1, 1);
+                window[0] = gf2::multmodp(IEEE_POLY, factor, IEEE.table()[1 << bit]);
+                n_bits += 1;
+
+                let width = (self.max_burst as usize).min(n_bits);
+                for pattern in (1..1u32 << width).step_by(2) {
+                    let mut candidate = 0;
+                    for (j, s) in window[..width].iter().enumerate() {
+                        if pattern >> j & 1 == 1 {
+                            candidate ^= s;
+                        }
+                    }
+                    if candidate == syndrome {
+                        if found.is_some() {
+                            return Err(Uncorrectable::Ambiguous);
+                        }
+                        found = Some(Correction::Data {
+                            bit: byte * 8 + bit,
+                            pattern,
+                        });
+                    }
+                }
+            }
+            factor = gf2::multmodp(IEEE_POLY, factor, X8);
+        }
+
+        match found {
+            Some(Correction::Data { bit, pattern }) => {
+                for j in 0..MAX_BURST as usize {
+                    if pattern >> j & 1 == 1 {
+                        frame[(bit + j) / 8] ^= 1 << ((bit + j) % 8);
+                    }
+                }
+                Ok(Correction::Data { bit, pattern })
+            }
+            Some(correction) => Ok(correction),
+            None => Err(Uncorrectable::NoMatch),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::*;
+
+    fn flip(frame: &mut [u8], bit: usize, pattern: u32) {
+        for j in 0..32 {
+            if pattern >> j & 1 == 1 {
+                frame[(bit + j) / 8] ^= 1 << ((bit + j) % 8);
+            }
+        }
+    }
+
+    #[test]
+    fn clean_frame() {
+        let mut frame = *b"123456789";
+        assert_eq!(
+            Corrector::new(16).correct(&mut frame, 0xcbf43926),
+            Ok(Correction::Clean)
+        );
+    }
+
+    #[test]
+    fn corrects_every_single_bit_error() {
+        let mut sent = [0u8; 32];
+        thread_rng().fill(&mut sent[..]);
+        let cksum = crc32(&sent);
+        let corrector = Corrector::new(32);
+
+        for bit in 0..sent.len() * 8 {
+            let mut received = sent;
+            flip(&mut received, bit, 1);
+            assert_eq!(
+                corrector.correct(&mut received, cksum),
+                Ok(Correction::Data { bit, pattern: 1 })
+            );
+            assert_eq!(received, sent);
+        }
+
+        for bit in 0..32 {
+            let mut received = sent;
+            assert_eq!(
+                corrector.correct(&mut received, cksum ^ 1 << bit),
+                Ok(Correction::Checksum { corrected: cksum })
+            );
+            assert_eq!(received, sent);
+        }
+    }
+
+    #[test]
+    fn corrects_bursts() {
+        let mut sent = [0u8; 64];
+        let mut rng = thread_rng();
+        rng.fill(&mut sent[..]);
+        let cksum = crc32(&sent);
+        let corrector = Corrector::new(64).with_max_burst(8);
+
+        for _ in 0..50 {
+            let len = rng.gen_range(1..=8);
+            let pattern = 1 | 1 << (len - 1) | rng.gen_range(0..1u32 << len);
+            let bit = rng.gen_range(0..sent.len() * 8 - len);
+            let mut received = sent;
+            flip(&mut received, bit, pattern);
+            assert_eq!(
+                corrector.correct(&mut received, cksum),
+                Ok(Correction::Data { bit, pattern })
+            );
+            assert_eq!(received, sent);
+        }
+    }
+
+    #[test]
+    fn rejects_what_it_cannot_correct() {
+        let mut frame = [0u8; 65];
+        assert_eq!(
+            Corrector::new(64).correct(&mut frame, 0),
+            Err(Uncorrectable::TooLong)
+        );
+
+        // a burst longer than the corrector searches for
+        let mut sent = [0u8; 16];
+        thread_rng().fill(&mut sent[..]);
+        let mut received = sent;
+        flip(&mut received, 20, 0b1000_0001);
+        let result = Corrector::new(16)
+            .with_max_burst(2)
+            .correct(&mut received, crc32(&sent));
+        assert!(matches!(
+            result,
+            Err(Uncorrectable::NoMatch | Uncorrectable::Ambiguous)
+        ));
+        flip(&mut received, 20, 0b1000_0001);
+        assert_eq!(received, sent);
+    }
+}
@@ -0,0 +1,249 @@
+//! `const fn` CRC-16 checksums.
+//!
+//! Each variant has a [u16; 256] lookup table computed at compile time. The `_seed` functions
+//! continue from `seed`, the checksum of the preceding data, so that
+//! `crc16_xmodem_seed(b, crc16_xmodem(a))` is the checksum of `a` followed by `b`. For the
+//! variants whose `init` differs from their `xorout` (CCITT-FALSE, MODBUS and MCRF4XX) a seed
+//! of `0u16` is *not* the same as starting fresh; use the unseeded function for the first part.
+
+use crate::{catalog, Crc};
+
+const IBM_3740: Crc<u16> = Crc::<u16>::new(catalog::CRC_16_IBM_3740);
+const XMODEM: Crc<u16> = Crc::<u16>::new(catalog::CRC_16_XMODEM);
+const KERMIT: Crc<u16> = Crc::<u16>::new(catalog::CRC_16_KERMIT);
+const MODBUS: Crc<u16> = Crc::<u16>::new(catalog::CRC_16_MODBUS);
+const USB: Crc<u16> = Crc::<u16>::new(catalog::CRC_16_USB);
+const ARC: Crc<u16> = Crc::<u16>::new(catalog::CRC_16_ARC);
+const IBM_SDLC: Crc<u16> = Crc::<u16>::new(catalog::CRC_16_IBM_SDLC);
+const MCRF4XX: Crc<u16> = Crc::<u16>::new(catalog::CRC_16_MCRF4XX);
+
+/// A `const fn` CRC-16/IBM-3740 checksum implementation.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// const CKSUM: u16 = const_crc32::crc16_ccitt_false(b"123456789");
+/// assert_eq!(CKSUM, 0x29b1_u16);
+/// ```
+pub const fn crc16_ccitt_false(buf: &[u8]) -> u16 {
+    IBM_3740.checksum(buf)
+}
+
+/// Calculate CRC-16/IBM-3740 checksum, continuing from `seed`, the checksum of the preceding data.
+#[inline]
+pub const fn crc16_ccitt_false_seed(buf: &[u8], seed: u16) -> u16 {
+    IBM_3740.checksum_seed(buf, seed)
+}
+
+/// A `const fn` CRC-16/XMODEM checksum implementation.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// const CKSUM: u16 = const_crc32::crc16_xmodem(b"123456789");
+/// assert_eq!(CKSUM, 0x31c3_u16);
+/// ```
+pub const fn crc16_xmodem(buf: &[u8]) -> u16 {
+    XMODEM.checksum(buf)
+}
+
+/// Calculate CRC-16/XMODEM checksum, continuing from `seed`, the checksum of the preceding data.
+#[inline]
+pub const fn crc16_xmodem_seed(buf: &[u8], seed: u16) -> u16 {
+    XMODEM.checksum_seed(buf, seed)
+}
+
+/// A `const fn` CRC-16/KERMIT checksum implementation.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// const CKSUM: u16 = const_crc32::crc16_kermit(b"123456789");
+/// assert_eq!(CKSUM, 0x2189_u16);
+/// ```
+pub const fn crc16_kermit(buf: &[u8]) -> u16 {
+    KERMIT.checksum(buf)
+}
+
+/// Calculate CRC-16/KERMIT checksum, continuing from `seed`, the checksum of the preceding data.
+#[inline]
+pub const fn crc16_kermit_seed(buf: &[u8], seed: u16) -> u16 {
+    KERMIT.checksum_seed(buf, seed)
+}
+
+/// A `const fn` CRC-16/MODBUS checksum implementation.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// const CKSUM: u16 = const_crc32::crc16_modbus(b"123456789");
+/// assert_eq!(CKSUM, 0x4b37_u16);
+/// ```
+pub const fn crc16_modbus(buf: &[u8]) -> u16 {
+    MODBUS.checksum(buf)
+}
+
+/// Calculate CRC-16/MODBUS checksum, continuing from `seed`, the checksum of the preceding data.
+#[inline]
+pub const fn crc16_modbus_seed(buf: &[u8], seed: u16) -> u16 {
+    MODBUS.checksum_seed(buf, seed)
+}
+
+/// A `const fn` CRC-16/USB checksum implementation.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// const CKSUM: u16 = const_crc32::crc16_usb(b"123456789");
+/// assert_eq!(CKSUM, 0xb4c8_u16);
+/// ```
+pub const fn crc16_usb(buf: &[u8]) -> u16 {
+    USB.checksum(buf)
+}
+
+/// Calculate CRC-16/USB checksum, continuing from `seed`, the checksum of the preceding data.
+#[inline]
+pub const fn crc16_usb_seed(buf: &[u8], seed: u16) -> u16 {
+    USB.checksum_seed(buf, seed)
+}
+
+/// A `const fn` CRC-16/ARC checksum implementation.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// const CKSUM: u16 = const_crc32::crc16_arc(b"123456789");
+/// assert_eq!(CKSUM, 0xbb3d_u16);
+/// ```
+pub const fn crc16_arc(buf: &[u8]) -> u16 {
+    ARC.checksum(buf)
+}
+
+/// Calculate CRC-16/ARC checksum, continuing from `seed`, the checksum of the preceding data.
+#[inline]
+pub const fn crc16_arc_seed(buf: &[u8], seed: u16) -> u16 {
+    ARC.checksum_seed(buf, seed)
+}
+
+/// A `const fn` CRC-16/IBM-SDLC checksum implementation.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// const CKSUM: u16 = const_crc32::crc16_x25(b"123456789");
+/// assert_eq!(CKSUM, 0x906e_u16);
+/// ```
+pub const fn crc16_x25(buf: &[u8]) -> u16 {
+    IBM_SDLC.checksum(buf)
+}
+
+/// Calculate CRC-16/IBM-SDLC checksum, continuing from `seed`, the checksum of the preceding data.
+#[inline]
+pub const fn crc16_x25_seed(buf: &[u8], seed: u16) -> u16 {
+    IBM_SDLC.checksum_seed(buf, seed)
+}
+
+/// A `const fn` CRC-16/MCRF4XX checksum implementation.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// const CKSUM: u16 = const_crc32::crc16_mcrf4xx(b"123456789");
+/// assert_eq!(CKSUM, 0x6f91_u16);
+/// ```
+pub const fn crc16_mcrf4xx(buf: &[u8]) -> u16 {
+    MCRF4XX.checksum(buf)
+}
+
+/// Calculate CRC-16/MCRF4XX checksum, continuing from `seed`, the checksum of the preceding data.
+#[inline]
+pub const fn crc16_mcrf4xx_seed(buf: &[u8], seed: u16) -> u16 {
+    MCRF4XX.checksum_seed(buf, seed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::*;
+
+    type Variant = (fn(&[u8]) -> u16, fn(&[u8], u16) -> u16, u16);
+
+    const VARIANTS: [Variant; 8] = [
+        (
+            crc16_ccitt_false,
+            crc16_ccitt_false_seed,
+            catalog::CRC_16_IBM_3740.check,
+        ),
+        (
+            crc16_xmodem,
+            crc16_xmodem_seed,
+            catalog::CRC_16_XMODEM.check,
+        ),
+        (
+            crc16_kermit,
+            crc16_kermit_seed,
+            catalog::CRC_16_KERMIT.check,
+        ),
+        (
+            crc16_modbus,
+            crc16_modbus_seed,
+            catalog::CRC_16_MODBUS.check,
+        ),
+        (crc16_usb, crc16_usb_seed, catalog::CRC_16_USB.check),
+        (crc16_arc, crc16_arc_seed, catalog::CRC_16_ARC.check),
+        (crc16_x25, crc16_x25_seed, catalog::CRC_16_IBM_SDLC.check),
+        (
+            crc16_mcrf4xx,
+            crc16_mcrf4xx_seed,
+            catalog::CRC_16_MCRF4XX.check,
+        ),
+    ];
+
+    #[test]
+    fn check_values() {
+        for (checksum, _, check) in VARIANTS {
+            assert_eq!(checksum(b"123456789"), check);
+        }
+    }
+
+    #[test]
+    fn check_values_at_compile_time() {
+        const CKSUMS: [u16; 8] = [
+            crc16_ccitt_false(b"123456789"),
+            crc16_xmodem(b"123456789"),
+            crc16_kermit(b"123456789"),
+            crc16_modbus(b"123456789"),
+            crc16_usb(b"123456789"),
+            crc16_arc(b"123456789"),
+            crc16_x25(b"123456789"),
+            crc16_mcrf4xx(b"123456789"),
+        ];
+        assert_eq!(
+            CKSUMS,
+            [0x29b1, 0x31c3, 0x2189, 0x4b37, 0xb4c8, 0xbb3d, 0x906e, 0x6f91]
+        );
+    }
+
+    #[test]
+    fn use_seed_to_checksum_from_many_chunks() {
+        let mut buf = [0u8; 1024];
+        let mut rng = thread_rng();
+        rng.fill(&mut buf[..]);
+
+        for (checksum, checksum_seed, _) in VARIANTS {
+            let mut cksum = checksum(&[]);
+            for chunk in buf[..].chunks(7) {
+                cksum = checksum_seed(chunk, cksum);
+            }
+            assert_eq!(cksum, checksum(&buf[..]));
+        }
+    }
+}
@@ -0,0 +1,148 @@
+//! `const fn` CRC-64 checksums.
+//!
+//! Each variant has a [u64; 256] lookup table computed at compile time. As with
+//! [`crc32_seed`](crate::crc32_seed), the `_seed` functions continue from the checksum of
+//! the preceding data, and a seed of `0u64` is the same as starting fresh.
+
+use crate::{catalog, Crc};
+
+const XZ: Crc<u64> = Crc::<u64>::new(catalog::CRC_64_XZ);
+const ECMA_182: Crc<u64> = Crc::<u64>::new(catalog::CRC_64_ECMA_182);
+const NVME: Crc<u64> = Crc::<u64>::new(catalog::CRC_64_NVME);
+const GO_ISO: Crc<u64> = Crc::<u64>::new(catalog::CRC_64_GO_ISO);
+
+/// A `const fn` CRC-64/XZ checksum implementation.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// const CKSUM: u64 = const_crc32::crc64_xz(b"123456789");
+/// assert_eq!(CKSUM, 0x995dc9bbdf1939fa_u64);
+/// ```
+pub const fn crc64_xz(buf: &[u8]) -> u64 {
+    crc64_xz_seed(buf, 0)
+}
+
+/// Calculate CRC-64/XZ checksum, using provided `seed` as the initial state.
+#[inline]
+pub const fn crc64_xz_seed(buf: &[u8], seed: u64) -> u64 {
+    XZ.checksum_seed(buf, seed)
+}
+
+/// A `const fn` CRC-64/ECMA-182 checksum implementation.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// const CKSUM: u64 = const_crc32::crc64_ecma_182(b"123456789");
+/// assert_eq!(CKSUM, 0x6c40df5f0b497347_u64);
+/// ```
+pub const fn crc64_ecma_182(buf: &[u8]) -> u64 {
+    crc64_ecma_182_seed(buf, 0)
+}
+
+/// Calculate CRC-64/ECMA-182 checksum, using provided `seed` as the initial state.
+#[inline]
+pub const fn crc64_ecma_182_seed(buf: &[u8], seed: u64) -> u64 {
+    ECMA_182.checksum_seed(buf, seed)
+}
+
+/// A `const fn` CRC-64/NVME checksum implementation.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// const CKSUM: u64 = const_crc32::crc64_nvme(b"123456789");
+/// assert_eq!(CKSUM, 0xae8b14860a799888_u64);
+/// ```
+pub const fn crc64_nvme(buf: &[u8]) -> u64 {
+    crc64_nvme_seed(buf, 0)
+}
+
+/// Calculate CRC-64/NVME checksum, using provided `seed` as the initial state.
+#[inline]
+pub const fn crc64_nvme_seed(buf: &[u8], seed: u64) -> u64 {
+    NVME.checksum_seed(buf, seed)
+}
+
+/// A `const fn` CRC-64/GO-ISO checksum implementation.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// const CKSUM: u64 = const_crc32::crc64_go_iso(b"123456789");
+/// assert_eq!(CKSUM, 0xb90956c775a41001_u64);
+/// ```
+pub const fn crc64_go_iso(buf: &[u8]) -> u64 {
+    crc64_go_iso_seed(buf, 0)
+}
+
+/// Calculate CRC-64/GO-ISO checksum, using provided `seed` as the initial state.
+#[inline]
+pub const fn crc64_go_iso_seed(buf: &[u8], seed: u64) -> u64 {
+    GO_ISO.checksum_seed(buf, seed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::*;
+
+    const CHECK: &[u8] = b"123456789";
+
+    #[test]
+    fn check_values() {
+        assert_eq!(crc64_xz(CHECK), catalog::CRC_64_XZ.check);
+        assert_eq!(crc64_ecma_182(CHECK), catalog::CRC_64_ECMA_182.check);
+        assert_eq!(crc64_nvme(CHECK), catalog::CRC_64_NVME.check);
+        assert_eq!(crc64_go_iso(CHECK), catalog::CRC_64_GO_ISO.check);
+    }
+
+    #[test]
+    fn check_values_at_compile_time() {
+        const CKSUMS: [u64; 4] = [
+            crc64_xz(CHECK),
+            crc64_ecma_182(CHECK),
+            crc64_nvme(CHECK),
+            crc64_go_iso(CHECK),
+        ];
+        assert_eq!(
+            CKSUMS,
+            [
+                0x995dc9bbdf1939fa,
+                0x6c40df5f0b497347,
+                0xae8b14860a799888,
+                0xb90956c775a41001
+            ]
+        );
+    }
+
+    #[test]
+    fn use_seed_to_checksum_from_many_chunks() {
+        let mut buf = [0u8; 1024];
+        let mut rng = thread_rng();
+        rng.fill(&mut buf[..]);
+
+        let mut cksums = [0u64; 4];
+        for chunk in buf[..].chunks(7) {
+            cksums[0] = crc64_xz_seed(chunk, cksums[0]);
+            cksums[1] = crc64_ecma_182_seed(chunk, cksums[1]);
+            cksums[2] = crc64_nvme_seed(chunk, cksums[2]);
+            cksums[3] = crc64_go_iso_seed(chunk, cksums[3]);
+        }
+
+        assert_eq!(
+            cksums,
+            [
+                crc64_xz(&buf[..]),
+                crc64_ecma_182(&buf[..]),
+                crc64_nvme(&buf[..]),
+                crc64_go_iso(&buf[..])
+            ]
+        );
+    }
+}
@@ -0,0 +1,120 @@
+//! [`digest`] trait implementations, enabled by the `digest` feature.
+//!
+//! The output is the 4-byte checksum in big-endian order, so the hex encoding of the digest
+//! reads the same as the checksum printed with `{:08x}`.
+//!
+//! Method calls like `hasher.update(data)` resolve to the inherent by-value methods of
+//! [`Crc32`] and [`Crc32c`], which return the new state instead of updating in place. Use
+//! `Digest::update(&mut hasher, data)` or `let hasher = hasher.update(data)` instead.
+
+use digest::consts::U4;
+use digest::{FixedOutput, FixedOutputReset, HashMarker, Output, OutputSizeUser, Reset, Update};
+
+use crate::{Crc32, Crc32c};
+
+macro_rules! impl_digest {
+    ($state:ty) => {
+        impl HashMarker for $state {}
+
+        impl OutputSizeUser for $state {
+            type OutputSize = U4;
+        }
+
+        impl Update for $state {
+            fn update(&mut self, data: &[u8]) {
+                *self = <$state>::update(*self, data);
+            }
+        }
+
+        impl FixedOutput for $state {
+            fn finalize_into(self, out: &mut Output<Self>) {
+                out.copy_from_slice(&<$state>::finalize(self).to_be_bytes());
+            }
+        }
+
+        impl Reset for $state {
+            fn reset(&mut self) {
+                *self = <$state>::reset(*self);
+            }
+        }
+
+        impl FixedOutputReset for $state {
+            fn finalize_into_reset(&mut self, out: &mut Output<Self>) {
+                out.copy_from_slice(&<$state>::finalize(*self).to_be_bytes());
+                Reset::reset(self);
+            }
+        }
+    };
+}
+
+impl_digest!(Crc32);
+impl_digest!(Crc32c);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{crc32, crc32c};
+    use digest::{Digest, DynDigest};
+
+    const BYTES: &[u8] = "The quick brown fox jumps over the lazy dog".as_bytes();
+
+    #[test]
+    fn digest_output_is_big_endian_checksum() {
+        assert_eq!(Crc32::digest(BYTES)[..], 0x414fa339_u32.to_be_bytes());
+        assert_eq!(Crc32c::digest(BYTES)[..], crc32c(BYTES).to_be_bytes());
+    }
+
+    #[test]
+    fn digest_update_and_reset() {
+        let mut hasher = <Crc32 as Digest>::new();
+        Digest::update(&mut hasher, &BYTES[..10]);
+        Digest::update(&mut hasher, &BYTES[10..]);
+        assert_eq!(hasher.finalize_reset()[..], crc32(BYTES).to_be_bytes());
+        assert_eq!(Digest::finalize(hasher)[..], crc32(&[]).to_be_bytes());
+    }
+
+    #[test]
+    fn reset_returns_to_seed() {
+        let seed = crc32(&BYTES[..10]);
+        let mut hasher = Crc32::with_seed(seed);
+        Digest::update(&mut hasher, &BYTES[10..]);
+        assert_eq!(hasher.finalize_reset()[..], crc32(BYTES).to_be_bytes());
+        Digest::update(&mut hasher, &BYTES[10..]);
+        assert_eq!(Digest::finalize(hasher)[..], crc32(BYTES).to_be_bytes());
+    }
+
+    #[test]
+    fn method_call_syntax_with_traits_in_scope() {
+        // `update`, `reset` and `finalize` are the inherent by-value methods here, `chain_update`
+        // comes from `Digest`
+        let hasher = Crc32::new().update(&BYTES[..10]);
+        let hasher = hasher.chain_update(&BYTES[10..]);
+        assert_eq!(hasher.finalize(), crc32(BYTES));
+
+        let hasher = hasher.reset().update(b"hello");
+        assert_eq!(hasher.finalize(), 0x3610a686);
+        assert_eq!(hasher.finalize_fixed()[..], 0x3610a686_u32.to_be_bytes());
+
+        let hasher = Crc32c::new().chain_update(BYTES).update(b"!");
+        assert_eq!(
+            hasher.finalize(),
+            crc32c(b"The quick brown fox jumps over the lazy dog!")
+        );
+    }
+
+    #[test]
+    fn usable_as_dyn_digest() {
+        let mut hashers: [&mut dyn DynDigest; 2] = [&mut Crc32::new(), &mut Crc32c::new()];
+        let mut out = [0u8; 4];
+
+        for hasher in hashers.iter_mut() {
+            hasher.update(BYTES);
+            assert_eq!(hasher.output_size(), 4);
+        }
+
+        hashers[0].finalize_into_reset(&mut out).unwrap();
+        assert_eq!(out, crc32(BYTES).to_be_bytes());
+        hashers[1].finalize_into_reset(&mut out).unwrap();
+        assert_eq!(out, crc32c(BYTES).to_be_bytes());
+    }
+}
@@ -0,0 +1,361 @@
+//! A `const fn` CRC engine parameterized by the Rocksoft model.
+//!
+//! An [`Algorithm`] describes a CRC (width, polynomial, initial value, reflection and final
+//! xor), and a [`Crc`] pairs it with a lookup table generated at compile time. The register
+//! type `W` is the smallest unsigned integer that holds `width` bits.
+
+/// Rocksoft model parameters of a CRC algorithm.
+///
+/// The field names and meanings follow the
+/// [catalogue of parametrised CRC algorithms](https://reveng.sourceforge.io/crc-catalogue/),
+/// with `poly`, `init` and `xorout` given in their unreflected form.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Algorithm<W> {
+    /// number of bits in the checksum
+    pub width: u8,
+    /// generator polynomial, without the leading `x^width` term
+    pub poly: W,
+    /// initial value of the register
+    pub init: W,
+    /// whether input bytes are processed least significant bit first
+    pub refin: bool,
+    /// whether the register is reflected before the final xor
+    pub refout: bool,
+    /// value xored into the register to produce the checksum
+    pub xorout: W,
+    /// checksum of the ascii string `"123456789"`
+    pub check: W,
+    /// register contents after processing a message followed by its own (unxored) checksum
+    pub residue: W,
+}
+
+/// A CRC algorithm together with its lookup table.
+///
+/// Declaring a `Crc` as a `const` computes the table at compile time:
+///
+/// ```
+/// use const_crc32_nostd::{catalog, Crc};
+///
+/// const CRC: Crc<u32> = Crc::<u32>::new(catalog::CRC_32_ISO_HDLC);
+/// const CKSUM: u32 = CRC.checksum(b"123456789");
+/// assert_eq!(CKSUM, catalog::CRC_32_ISO_HDLC.check);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Crc<W> {
+    algorithm: Algorithm<W>,
+    table: [W; 256],
+}
+
+macro_rules! impl_crc {
+    ($w:ty) => {
+        impl Crc<$w> {
+            /// Build the lookup table for `algorithm`.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `algorithm.width` is zero or wider than the register type.
+            pub const fn new(algorithm: Algorithm<$w>) -> Self {
+                assert!(algorithm.width > 0 && algorithm.width as u32 <= <$w>::BITS);
+
+                let mut table: [$w; 256] = [0; 256];
+                let mut i = 0;
+
+                while i < 256 {
+                    table[i] = Self::table_entry(&algorithm, i as u8);
+                    i += 1;
+                }
+
+                Self { algorithm, table }
+            }
+
+            /// computes a single entry of the lookup table. reflected algorithms keep the
+            /// register in the low `width` bits; unreflected ones keep it in the high `width`
+            /// bits of `$w`, so that widths under 8 bits line up with the input byte.
+            const fn table_entry(algorithm: &Algorithm<$w>, i: u8) -> $w {
+                let mut out: $w;
+                let mut bit = 0;
+
+                if algorithm.refin {
+                    let poly = Self::reflect(algorithm.poly, algorithm.width);
+                    out = i as $w;
+                    while bit < 8 {
+                        out = if out & 1 == 1 {
+                            poly ^ (out >> 1)
+                        } else {
+                            out >> 1
+                        };
+                        bit += 1;
+                    }
+                } else {
+                    let poly = algorithm.poly << (<$w>::BITS - algorithm.width as u32);
+                    out = (i as $w) << (<$w>::BITS - 8);
+                    while bit < 8 {
+                        out = if out >> (<$w>::BITS - 1) == 1 {
+                            poly ^ (out << 1)
+                        } else {
+                            out << 1
+                        };
+                        bit += 1;
+                    }
+                }
+
+                out
+            }
+
+            /// The parameters this `Crc` was built from.
+            pub const fn algorithm(&self) -> &Algorithm<$w> {
+                &self.algorithm
+            }
+
+            /// The 256-entry lookup table.
+            pub const fn table(&self) -> &[$w; 256] {
+                &self.table
+            }
+
+            /// Initial register value, to be passed to [`update`](Self::update).
+            pub const fn init(&self) -> $w {
+                self.register(self.algorithm.init)
+            }
+
+            /// Recover the register from a finished checksum, so that processing can continue
+            /// where a previous call to [`finalize`](Self::finalize) left off.
+            pub const fn resume(&self, checksum: $w) -> $w {
+                let mut value =
+                    (checksum ^ self.algorithm.xorout) & Self::mask(self.algorithm.width);
+                if self.algorithm.refin != self.algorithm.refout {
+                    value = Self::reflect(value, self.algorithm.width);
+                }
+                if self.algorithm.refin {
+                    value
+                } else {
+                    self.register(value)
+                }
+            }
+
+            /// Feed `buf` through the register `crc`, returning the new register.
+            pub const fn update(&self, crc: $w, buf: &[u8]) -> $w {
+                let mut out = crc;
+                let mut i = 0usize;
+
+                if self.algorithm.refin {
+                    while i < buf.len() {
+                        out = Self::shr8(out) ^ self.table[(out as u8 ^ buf[i]) as usize];
+                        i += 1;
+                    }
+                } else {
+                    while i < buf.len() {
+                        out = Self::shl8(out)
+                            ^ self.table[((out >> (<$w>::BITS - 8)) as u8 ^ buf[i]) as usize];
+                        i += 1;
+                    }
+                }
+
+                out
+            }
+
+            /// Produce the checksum from the register `crc`.
+            pub const fn finalize(&self, crc: $w) -> $w {
+                let mut value = if self.algorithm.refin {
+                    crc
+                } else {
+                    crc >> (<$w>::BITS - self.algorithm.width as u32)
+                };
+                if self.algorithm.refin != self.algorithm.refout {
+                    value = Self::reflect(value, self.algorithm.width);
+                }
+                (value ^ self.algorithm.xorout) & Self::mask(self.algorithm.width)
+            }
+
+            /// Checksum of `buf`.
+            pub const fn checksum(&self, buf: &[u8]) -> $w {
+                self.finalize(self.update(self.init(), buf))
+            }
+
+            /// Checksum of `buf`, continuing from `seed`, the checksum of the preceding data.
+            ///
+            /// `checksum_seed(b, checksum(a))` equals the checksum of `a` followed by `b`.
+            pub const fn checksum_seed(&self, buf: &[u8], seed: $w) -> $w {
+                self.finalize(self.update(self.resume(seed), buf))
+            }
+
+            /// converts an unreflected `width`-bit value into the register layout used by the table
+            const fn register(&self, value: $w) -> $w {
+                if self.algorithm.refin {
+                    Self::reflect(value, self.algorithm.width)
+                } else {
+                    value << (<$w>::BITS - self.algorithm.width as u32)
+                }
+            }
+
+            /// reverses the low `width` bits of `value`
+            const fn reflect(value: $w, width: u8) -> $w {
+                value.reverse_bits() >> (<$w>::BITS - width as u32)
+            }
+
+            const fn mask(width: u8) -> $w {
+                <$w>::MAX >> (<$w>::BITS - width as u32)
+            }
+
+            // shifting a `u8` by 8 overflows, so these go through `checked_*`
+
+            const fn shr8(value: $w) -> $w {
+                match value.checked_shr(8) {
+                    Some(out) => out,
+                    None => 0,
+                }
+            }
+
+            const fn shl8(value: $w) -> $w {
+                match value.checked_shl(8) {
+                    Some(out) => out,
+                    None => 0,
+                }
+            }
+        }
+    };
+}
+
+impl_crc!(u8);
+impl_crc!(u16);
+impl_crc!(u32);
+impl_crc!(u64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog;
+
+    use rand::prelude::*;
+
+    const CHECK: &[u8] = b"123456789";
+
+    /// bit-at-a-time reference implementation straight from the Rocksoft model
+    fn bitwise<W: Into<u64> + Copy>(algorithm: &Algorithm<W>, buf: &[u8]) -> u64 {
+        let width = algorithm.width as u32;
+        let mask = u64::MAX >> (64 - width);
+        let mut reg: u64 = algorithm.init.into();
+
+        for &byte in buf {
+            let byte = if algorithm.refin {
+                byte.reverse_bits()
+            } else {
+                byte
+            };
+            for i in (0..8).rev() {
+                let feedback = (reg >> (width - 1)) & 1 ^ (byte >> i) as u64 & 1;
+                reg = (reg << 1) & mask;
+                if feedback == 1 {
+                    reg ^= algorithm.poly.into();
+                }
+            }
+        }
+
+        if algorithm.refout {
+            reg = reg.reverse_bits() >> (64 - width);
+        }
+        reg ^ algorithm.xorout.into()
+    }
+
+    macro_rules! check_against_bitwise {
+        ($w:ty: $($algorithm:ident),*) => {
+            let mut buf = [0u8; 257];
+            let mut rng = thread_rng();
+            rng.fill(&mut buf[..]);
+
+            $(
+                let crc = Crc::<$w>::new(catalog::$algorithm);
+                for len in [0, 1, 2, 9, 257] {
+                    assert_eq!(
+                        crc.checksum(&buf[..len]) as u64,
+                        bitwise(&catalog::$algorithm, &buf[..len]),
+                        "{} over {} bytes",
+                        stringify!($algorithm),
+                        len,
+                    );
+                }
+            )*
+        };
+    }
+
+    #[test]
+    fn narrow_widths_match_bitwise_reference() {
+        check_against_bitwise!(u8: CRC_3_GSM, CRC_3_ROHC, CRC_4_G_704, CRC_5_USB, CRC_5_EPC_C1G2,
+            CRC_6_G_704, CRC_7_MMC, CRC_8_SMBUS, CRC_8_MAXIM_DOW);
+        check_against_bitwise!(u16: CRC_10_ATM, CRC_11_FLEXRAY, CRC_12_DECT, CRC_15_CAN,
+            CRC_16_IBM_3740, CRC_16_KERMIT);
+    }
+
+    #[test]
+    fn wide_widths_match_bitwise_reference() {
+        check_against_bitwise!(u32: CRC_32_ISO_HDLC, CRC_32_BZIP2);
+        check_against_bitwise!(u64: CRC_64_XZ, CRC_64_ECMA_182);
+    }
+
+    #[test]
+    fn crc32_iso_hdlc_check_value() {
+        const CRC: Crc<u32> = Crc::<u32>::new(catalog::CRC_32_ISO_HDLC);
+        assert_eq!(CRC.checksum(CHECK), catalog::CRC_32_ISO_HDLC.check);
+    }
+
+    #[test]
+    fn unreflected_algorithm_check_value() {
+        // CRC-16/XMODEM
+        const CRC: Crc<u16> = Crc::<u16>::new(Algorithm {
+            width: 16,
+            poly: 0x1021,
+            init: 0x0000,
+            refin: false,
+            refout: false,
+            xorout: 0x0000,
+            check: 0x31c3,
+            residue: 0x0000,
+        });
+        assert_eq!(CRC.checksum(CHECK), 0x31c3);
+    }
+
+    #[test]
+    fn mixed_reflection_check_value() {
+        // CRC-12/UMTS: unreflected input, reflected output
+        const CRC: Crc<u16> = Crc::<u16>::new(Algorithm {
+            width: 12,
+            poly: 0x80f,
+            init: 0x000,
+            refin: false,
+            refout: true,
+            xorout: 0x000,
+            check: 0xdaf,
+            residue: 0x000,
+        });
+        assert_eq!(CRC.checksum(CHECK), 0xdaf);
+        assert_eq!(
+            CRC.checksum_seed(&CHECK[4..], CRC.checksum(&CHECK[..4])),
+            0xdaf
+        );
+    }
+
+    #[test]
+    fn full_width_u64_check_value() {
+        // CRC-64/XZ
+        const CRC: Crc<u64> = Crc::<u64>::new(Algorithm {
+            width: 64,
+            poly: 0x42f0e1eba9ea3693,
+            init: 0xffffffffffffffff,
+            refin: true,
+            refout: true,
+            xorout: 0xffffffffffffffff,
+            check: 0x995dc9bbdf1939fa,
+            residue: 0x49958c9abd7d353f,
+        });
+        assert_eq!(CRC.checksum(CHECK), 0x995dc9bbdf1939fa);
+    }
+
+    #[test]
+    fn resume_from_empty_checksum_matches_init() {
+        const CRC: Crc<u32> = Crc::<u32>::new(catalog::CRC_32_ISO_HDLC);
+        assert_eq!(CRC.resume(CRC.checksum(&[])), CRC.init());
+        assert_eq!(
+            CRC.checksum_seed(CHECK, CRC.checksum(&[])),
+            CRC.checksum(CHECK)
+        );
+    }
+}
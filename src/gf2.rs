@@ -0,0 +1,134 @@
+//! Polynomial arithmetic modulo a reflected 32-bit CRC polynomial.
+//!
+//! A CRC register is the message polynomial modulo P(x), so appending `n` zero bytes
+//! multiplies the register by x^(8n) mod P(x). These helpers compute such products directly,
+//! in O(log n) multiplications instead of `n` table lookups. Polynomials are bit-reflected:
+//! the most significant bit holds the coefficient of x^0.
+
+/// `a * b mod P(x)`, where `poly` is the reflected polynomial, e.g. `0xedb88320`
+pub(crate) const fn multmodp(poly: u32, a: u32, b: u32) -> u32 {
+    let mut m = 1u32 << 31;
+    let mut p = 0u32;
+    let mut b = b;
+
+    while m != 0 {
+        if a & m != 0 {
+            p ^= b;
+        }
+        m >>= 1;
+        b = if b & 1 == 1 { (b >> 1) ^ poly } else { b >> 1 };
+    }
+
+    p
+}
+
+/// `x^(8n) mod P(x)`, the factor that appends `n` zero bytes to a register
+pub(crate) const fn xpow8n(poly: u32, n: u64) -> u32 {
+    let mut p = 1u32 << 31; // x^0
+    let mut sq = 1u32 << (31 - 8); // x^8, squared for each bit of `n`
+    let mut n = n;
+
+    while n != 0 {
+        if n & 1 == 1 {
+            p = multmodp(poly, p, sq);
+        }
+        sq = multmodp(poly, sq, sq);
+        n >>= 1;
+    }
+
+    p
+}
+
+/// applies `reg -> reg * factor + term` to `reg` `n` times, in O(log n) multiplications.
+/// with `factor = x^(8k)` and `term` the register contribution of a `k`-byte block, this
+/// appends `n` copies of the block
+pub(crate) const fn repeat(poly: u32, reg: u32, factor: u32, term: u32, n: u64) -> u32 {
+    // `(a, b)` stands for the map `r -> r * a + b`; `(f, t)` is squared for each bit of `n`
+    let (mut a, mut b) = (1u32 << 31, 0u32);
+    let (mut f, mut t) = (factor, term);
+    let mut n = n;
+
+    while n != 0 {
+        if n & 1 == 1 {
+            a = multmodp(poly, a, f);
+            b = multmodp(poly, b, f) ^ t;
+        }
+        t = multmodp(poly, t, f) ^ t;
+        f = multmodp(poly, f, f);
+        n >>= 1;
+    }
+
+    multmodp(poly, reg, a) ^ b
+}
+
+#[cfg(all(feature = "std", target_arch = "x86_64"))]
+/// lookup tables multiplying a register by `x^(8n) mod P(x)` one byte at a time, for a fixed `n`
+pub(crate) const fn shift_tables(poly: u32, n: u64) -> [[u32; 256]; 4] {
+    let factor = xpow8n(poly, n);
+    let mut tables = [[0u32; 256]; 4];
+
+    let mut k = 0;
+    while k < 4 {
+        let mut i = 0;
+        while i < 256 {
+            tables[k][i] = multmodp(poly, factor, (i as u32) << (8 * k));
+            i += 1;
+        }
+        k += 1;
+    }
+
+    tables
+}
+
+#[cfg(all(feature = "std", target_arch = "x86_64"))]
+/// multiplies `crc` by the factor `tables` was built for
+pub(crate) const fn shift(tables: &[[u32; 256]; 4], crc: u32) -> u32 {
+    tables[0][(crc & 0xff) as usize]
+        ^ tables[1][((crc >> 8) & 0xff) as usize]
+        ^ tables[2][((crc >> 16) & 0xff) as usize]
+        ^ tables[3][(crc >> 24) as usize]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{catalog, Crc};
+
+    const POLY: u32 = 0x82f63b78;
+    const CRC: Crc<u32> = Crc::<u32>::new(catalog::CRC_32_ISCSI);
+
+    #[test]
+    fn xpow8n_appends_zero_bytes() {
+        let reg = CRC.update(CRC.init(), b"123456789");
+        for n in [0, 1, 2, 3, 7, 64, 1000] {
+            let zeros = [0u8; 1000];
+            assert_eq!(
+                multmodp(POLY, xpow8n(POLY, n as u64), reg),
+                CRC.update(reg, &zeros[..n])
+            );
+        }
+    }
+
+    #[test]
+    fn repeat_appends_copies_of_a_block() {
+        let block = b"\x55\xaa\x01";
+        let term = CRC.update(0, block);
+        let factor = xpow8n(POLY, block.len() as u64);
+
+        let mut reg = CRC.update(CRC.init(), b"123456789");
+        let start = reg;
+        for n in 0..40 {
+            assert_eq!(repeat(POLY, start, factor, term, n), reg);
+            reg = CRC.update(reg, block);
+        }
+    }
+
+    #[cfg(all(feature = "std", target_arch = "x86_64"))]
+    #[test]
+    fn shift_tables_match_multmodp() {
+        const TABLES: [[u32; 256]; 4] = shift_tables(POLY, 300);
+        for reg in [0, 1, 0xdeadbeef, 0xffffffff, 0x80000000] {
+            assert_eq!(shift(&TABLES, reg), multmodp(POLY, xpow8n(POLY, 300), reg));
+        }
+    }
+}
@@ -0,0 +1,397 @@
+//! Streaming checksum state, and [`Hasher`] implementations on top of it.
+
+use core::hash::{BuildHasher, Hasher};
+
+use crate::{slice, CASTAGNOLI, CASTAGNOLI_SLICE16, IEEE, IEEE_SLICE16};
+
+/// odd constant (2^64 divided by the golden ratio) that [`Hasher::finish`] multiplies the
+/// checksum by. hash tables like hashbrown take bits from the top of the `u64` hash, which
+/// would always be zero for a checksum that is only zero-extended
+const SPREAD: u64 = 0x9e3779b97f4a7c15;
+
+/// Streaming crc32 state.
+///
+/// Holds the raw CRC register, so there is no need to thread the finished checksum through
+/// repeated calls to [`crc32_seed`](crate::crc32_seed). All methods are `const fn`, so it can
+/// be used in `const` contexts as well as at runtime.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd::{crc32, Crc32};
+///
+/// const CKSUM: u32 = Crc32::new()
+///     .update(b"The quick brown fox ")
+///     .update(b"jumps over the lazy dog")
+///     .finalize();
+///
+/// assert_eq!(CKSUM, crc32(b"The quick brown fox jumps over the lazy dog"));
+/// ```
+#[derive(Clone, Copy, Debug, Eq)]
+pub struct Crc32 {
+    register: u32,
+    /// register to return to on [`reset`](Self::reset)
+    start: u32,
+}
+
+impl Crc32 {
+    /// State for an empty input.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    /// State continuing from `seed`, with the same meaning as the `seed` of
+    /// [`crc32_seed`](crate::crc32_seed).
+    #[must_use]
+    pub const fn with_seed(seed: u32) -> Self {
+        let register = IEEE.resume(seed);
+        Self {
+            register,
+            start: register,
+        }
+    }
+
+    /// Feed `buf` into the checksum.
+    ///
+    /// The updated state is returned and `self` is left as it was, so the result has to be used:
+    ///
+    /// ```compile_fail
+    /// #![deny(unused_must_use)]
+    /// use const_crc32_nostd::Crc32;
+    ///
+    /// let state = Crc32::new();
+    /// state.update(b"dropped on the floor");
+    /// ```
+    #[must_use = "this returns the updated state, without modifying the original"]
+    pub const fn update(self, buf: &[u8]) -> Self {
+        Self {
+            register: slice::update_slice16(&IEEE_SLICE16, self.register, buf),
+            start: self.start,
+        }
+    }
+
+    /// Discard everything fed in so far, returning to the state `self` was created with,
+    /// including its seed.
+    #[must_use = "this returns the reset state, without modifying the original"]
+    pub const fn reset(self) -> Self {
+        Self {
+            register: self.start,
+            start: self.start,
+        }
+    }
+
+    /// The checksum of everything fed in so far. `self` is left untouched, so more data can
+    /// still be added afterwards.
+    pub const fn finalize(self) -> u32 {
+        IEEE.finalize(self.register)
+    }
+}
+
+/// States are equal when they will produce the same checksums, whatever they reset to.
+impl PartialEq for Crc32 {
+    fn eq(&self, other: &Self) -> bool {
+        self.register == other.register
+    }
+}
+
+impl Default for Crc32 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Streaming crc32c state, the CRC-32C counterpart of [`Crc32`].
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd::{crc32c, Crc32c};
+///
+/// const CKSUM: u32 = Crc32c::new().update(b"1234").update(b"56789").finalize();
+///
+/// assert_eq!(CKSUM, crc32c(b"123456789"));
+/// ```
+#[derive(Clone, Copy, Debug, Eq)]
+pub struct Crc32c {
+    register: u32,
+    /// register to return to on [`reset`](Self::reset)
+    start: u32,
+}
+
+impl Crc32c {
+    /// State for an empty input.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    /// State continuing from `seed`, with the same meaning as the `seed` of
+    /// [`crc32c_seed`](crate::crc32c_seed).
+    #[must_use]
+    pub const fn with_seed(seed: u32) -> Self {
+        let register = CASTAGNOLI.resume(seed);
+        Self {
+            register,
+            start: register,
+        }
+    }
+
+    /// Feed `buf` into the checksum.
+    #[must_use = "this returns the updated state, without modifying the original"]
+    pub const fn update(self, buf: &[u8]) -> Self {
+        Self {
+            register: slice::update_slice16(&CASTAGNOLI_SLICE16, self.register, buf),
+            start: self.start,
+        }
+    }
+
+    /// Discard everything fed in so far, returning to the state `self` was created with,
+    /// including its seed.
+    #[must_use = "this returns the reset state, without modifying the original"]
+    pub const fn reset(self) -> Self {
+        Self {
+            register: self.start,
+            start: self.start,
+        }
+    }
+
+    /// The checksum of everything fed in so far. `self` is left untouched, so more data can
+    /// still be added afterwards.
+    pub const fn finalize(self) -> u32 {
+        CASTAGNOLI.finalize(self.register)
+    }
+}
+
+/// States are equal when they will produce the same checksums, whatever they reset to.
+impl PartialEq for Crc32c {
+    fn eq(&self, other: &Self) -> bool {
+        self.register == other.register
+    }
+}
+
+impl Default for Crc32c {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Hasher for Crc32 {
+    fn write(&mut self, bytes: &[u8]) {
+        *self = self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        (self.finalize() as u64).wrapping_mul(SPREAD)
+    }
+}
+
+impl Hasher for Crc32c {
+    fn write(&mut self, bytes: &[u8]) {
+        *self = self.update(bytes);
+    }
+
+    fn finish(&self) -> u64 {
+        (self.finalize() as u64).wrapping_mul(SPREAD)
+    }
+}
+
+/// [`BuildHasher`] producing [`Crc32`] hashers that all start from the same seed.
+///
+/// This is not a DoS-resistant hasher, so it should only be used for keys that are not
+/// attacker controlled.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd::BuildCrc32;
+/// use std::collections::HashMap;
+///
+/// const BUILD_HASHER: BuildCrc32 = BuildCrc32::with_seed(0x2bad2bad);
+///
+/// let mut map = HashMap::with_hasher(BUILD_HASHER);
+/// map.insert("bump!", 1);
+/// assert_eq!(map.get("bump!"), Some(&1));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BuildCrc32 {
+    seed: u32,
+}
+
+impl BuildCrc32 {
+    /// Hashers starting from the state of an empty input.
+    pub const fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    /// Hashers starting from `seed`, see [`Crc32::with_seed`].
+    pub const fn with_seed(seed: u32) -> Self {
+        Self { seed }
+    }
+}
+
+impl BuildHasher for BuildCrc32 {
+    type Hasher = Crc32;
+
+    fn build_hasher(&self) -> Crc32 {
+        Crc32::with_seed(self.seed)
+    }
+}
+
+/// [`BuildHasher`] producing [`Crc32c`] hashers that all start from the same seed.
+///
+/// This is not a DoS-resistant hasher, so it should only be used for keys that are not
+/// attacker controlled.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd::BuildCrc32c;
+/// use std::collections::HashMap;
+///
+/// let mut map = HashMap::with_hasher(BuildCrc32c::new());
+/// map.insert("thump!", 2);
+/// assert_eq!(map.get("thump!"), Some(&2));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BuildCrc32c {
+    seed: u32,
+}
+
+impl BuildCrc32c {
+    /// Hashers starting from the state of an empty input.
+    pub const fn new() -> Self {
+        Self::with_seed(0)
+    }
+
+    /// Hashers starting from `seed`, see [`Crc32c::with_seed`].
+    pub const fn with_seed(seed: u32) -> Self {
+        Self { seed }
+    }
+}
+
+impl BuildHasher for BuildCrc32c {
+    type Hasher = Crc32c;
+
+    fn build_hasher(&self) -> Crc32c {
+        Crc32c::with_seed(self.seed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{crc32, crc32_seed, crc32c, crc32c_seed};
+    use rand::prelude::*;
+
+    #[test]
+    fn update_from_many_chunks() {
+        let mut buf = [0u8; 1024];
+        let mut rng = thread_rng();
+        rng.fill(&mut buf[..]);
+
+        let mut state = Crc32::new();
+        let mut len = 0;
+        for chunk in buf[..].chunks(7) {
+            state = state.update(chunk);
+            len += chunk.len();
+            assert_eq!(state.finalize(), crc32(&buf[..len]));
+        }
+
+        assert_eq!(state.finalize(), crc32(&buf[..]));
+        assert_eq!(Crc32::default().finalize(), crc32(&[]));
+    }
+
+    #[test]
+    fn with_seed_matches_crc32_seed() {
+        const BYTES: &[u8] = "The quick brown fox jumps over the lazy dog".as_bytes();
+        let seed = crc32(&BYTES[..10]);
+        assert_eq!(
+            Crc32::with_seed(seed).update(&BYTES[10..]).finalize(),
+            crc32_seed(&BYTES[10..], seed)
+        );
+        assert_eq!(Crc32::with_seed(0xbaaaaaad).finalize(), 0xbaaaaaad);
+    }
+
+    #[test]
+    fn crc32c_with_seed_matches_crc32c_seed() {
+        const BYTES: &[u8] = "The quick brown fox jumps over the lazy dog".as_bytes();
+        let seed = crc32c(&BYTES[..10]);
+        assert_eq!(
+            Crc32c::with_seed(seed).update(&BYTES[10..]).finalize(),
+            crc32c_seed(&BYTES[10..], seed)
+        );
+        assert_eq!(Crc32c::new().update(BYTES).finalize(), crc32c(BYTES));
+    }
+
+    #[test]
+    fn reset_keeps_seed() {
+        let seeded = Crc32::with_seed(0xbaaaaaad);
+        assert_eq!(seeded.update(b"bump!").reset(), seeded);
+        let seeded = Crc32c::with_seed(0x2bad2bad);
+        assert_eq!(seeded.update(b"thump!").reset().finalize(), 0x2bad2bad);
+    }
+
+    #[test]
+    fn equality_ignores_reset_point() {
+        assert_eq!(Crc32::new().update(b"ab"), Crc32::with_seed(crc32(b"ab")));
+        assert_eq!(
+            Crc32c::new().update(b"ab"),
+            Crc32c::with_seed(crc32c(b"ab"))
+        );
+        assert_ne!(Crc32::new().update(b"ab"), Crc32::new().update(b"ba"));
+    }
+
+    #[test]
+    fn hasher_write_matches_seeded_checksum() {
+        let mut hasher = BuildCrc32c::with_seed(0x2bad2bad).build_hasher();
+        hasher.write(b"bump! ");
+        hasher.write(b"thump!");
+        assert_eq!(
+            hasher.finish(),
+            (crc32c_seed(b"bump! thump!", 0x2bad2bad) as u64).wrapping_mul(SPREAD)
+        );
+
+        let mut hasher = BuildCrc32::new().build_hasher();
+        hasher.write(b"bump! thump!");
+        assert_eq!(
+            hasher.finish(),
+            (crc32(b"bump! thump!") as u64).wrapping_mul(SPREAD)
+        );
+    }
+
+    #[test]
+    fn seeds_change_hashes() {
+        let a = BuildCrc32c::with_seed(0xbaaaaaad).hash_one("bump! thump!");
+        let b = BuildCrc32c::with_seed(0x2bad2bad).hash_one("bump! thump!");
+        assert_ne!(a, b);
+        assert_eq!(
+            a,
+            BuildCrc32c::with_seed(0xbaaaaaad).hash_one("bump! thump!")
+        );
+    }
+
+    #[test]
+    fn hashes_use_the_high_bits() {
+        let mut high_bits = 0u64;
+        for i in 0..64u32 {
+            high_bits |= BuildCrc32::new().hash_one(i) >> 57;
+            high_bits |= BuildCrc32c::new().hash_one(i) >> 57;
+        }
+        assert_eq!(high_bits, 0x7f);
+    }
+
+    #[test]
+    fn usable_as_hash_map_hasher() {
+        extern crate std;
+        use std::collections::HashMap;
+
+        let mut map = HashMap::with_hasher(BuildCrc32c::new());
+        for i in 0..1000u32 {
+            map.insert(i, i * 2);
+        }
+        for i in 0..1000u32 {
+            assert_eq!(map[&i], i * 2);
+        }
+    }
+}
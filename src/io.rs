@@ -0,0 +1,292 @@
+//! [`std::io`] adaptors that checksum the bytes passing through them.
+//!
+//! Requires the `std` feature.
+
+use std::io::{self, Read, Write};
+
+use crate::{ChecksumMismatch, Crc32};
+
+/// A [`Write`] adaptor computing the crc32 of everything written to the inner writer.
+///
+/// Only bytes the inner writer accepted are checksummed, so short writes are accounted for.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd::{crc32, io::Crc32Writer};
+/// use std::io::Write;
+///
+/// let mut writer = Crc32Writer::new(Vec::new());
+/// writer.write_all(b"The quick brown fox jumps over the lazy dog").unwrap();
+///
+/// let (buf, cksum) = writer.into_parts();
+/// assert_eq!(cksum, crc32(&buf));
+/// ```
+#[derive(Debug)]
+pub struct Crc32Writer<W> {
+    inner: W,
+    crc: Crc32,
+}
+
+impl<W: Write> Crc32Writer<W> {
+    /// Wrap `inner`, starting from the checksum of an empty input.
+    pub fn new(inner: W) -> Self {
+        Self::with_crc(inner, Crc32::new())
+    }
+
+    /// Wrap `inner`, continuing from the checksum state `crc`.
+    pub fn with_crc(inner: W, crc: Crc32) -> Self {
+        Self { inner, crc }
+    }
+}
+
+impl<W> Crc32Writer<W> {
+    /// The crc32 of everything written so far.
+    pub fn checksum(&self) -> u32 {
+        self.crc.finalize()
+    }
+
+    /// A reference to the inner writer.
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// A mutable reference to the inner writer. Bytes written through it are not checksummed.
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Unwrap the inner writer, discarding the checksum.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Unwrap the inner writer together with the checksum of everything written.
+    pub fn into_parts(self) -> (W, u32) {
+        let cksum = self.checksum();
+        (self.inner, cksum)
+    }
+}
+
+impl<W: Write> Write for Crc32Writer<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.crc = self.crc.update(&buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// A [`Read`] adaptor computing the crc32 of everything read from the inner reader.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd::{crc32, io::Crc32Reader};
+///
+/// const BYTES: &[u8] = "The quick brown fox jumps over the lazy dog".as_bytes();
+///
+/// let mut reader = Crc32Reader::new(BYTES);
+/// std::io::copy(&mut reader, &mut std::io::sink()).unwrap();
+///
+/// assert_eq!(reader.checksum(), crc32(BYTES));
+/// ```
+#[derive(Debug)]
+pub struct Crc32Reader<R> {
+    inner: R,
+    crc: Crc32,
+}
+
+impl<R: Read> Crc32Reader<R> {
+    /// Wrap `inner`, starting from the checksum of an empty input.
+    pub fn new(inner: R) -> Self {
+        Self::with_crc(inner, Crc32::new())
+    }
+
+    /// Wrap `inner`, continuing from the checksum state `crc`.
+    pub fn with_crc(inner: R, crc: Crc32) -> Self {
+        Self { inner, crc }
+    }
+}
+
+impl<R> Crc32Reader<R> {
+    /// The crc32 of everything read so far.
+    pub fn checksum(&self) -> u32 {
+        self.crc.finalize()
+    }
+
+    /// A reference to the inner reader.
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// A mutable reference to the inner reader. Bytes read through it are not checksummed.
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Unwrap the inner reader, discarding the checksum.
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> Read for Crc32Reader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.crc = self.crc.update(&buf[..n]);
+        Ok(n)
+    }
+}
+
+/// A [`Read`] adaptor that checks the crc32 of everything read against an expected value.
+///
+/// Once the inner reader reaches end of file, a read returns an error of kind
+/// [`io::ErrorKind::InvalidData`] instead of `Ok(0)` if the checksum does not match, wrapping
+/// a [`ChecksumMismatch`]. Data is passed through as it arrives, so callers must not act on it
+/// until EOF has been reached without error.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd::{crc32, io::Crc32VerifyingReader};
+/// use std::io::Read;
+///
+/// const BYTES: &[u8] = "The quick brown fox jumps over the lazy dog".as_bytes();
+///
+/// let mut buf = Vec::new();
+/// let mut reader = Crc32VerifyingReader::new(BYTES, crc32(BYTES));
+/// assert!(reader.read_to_end(&mut buf).is_ok());
+///
+/// let mut reader = Crc32VerifyingReader::new(BYTES, 0xbaaaaaad);
+/// let err = reader.read_to_end(&mut buf).unwrap_err();
+/// assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+/// ```
+#[derive(Debug)]
+pub struct Crc32VerifyingReader<R> {
+    inner: Crc32Reader<R>,
+    expected: u32,
+}
+
+impl<R: Read> Crc32VerifyingReader<R> {
+    /// Wrap `inner`, expecting the crc32 of its contents to be `expected`.
+    pub fn new(inner: R, expected: u32) -> Self {
+        Self {
+            inner: Crc32Reader::new(inner),
+            expected,
+        }
+    }
+}
+
+impl<R> Crc32VerifyingReader<R> {
+    /// The crc32 of everything read so far.
+    pub fn checksum(&self) -> u32 {
+        self.inner.checksum()
+    }
+
+    /// The checksum expected at end of file.
+    pub fn expected(&self) -> u32 {
+        self.expected
+    }
+
+    /// Unwrap the inner reader.
+    pub fn into_inner(self) -> R {
+        self.inner.into_inner()
+    }
+}
+
+impl<R: Read> Read for Crc32VerifyingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        if n == 0 && !buf.is_empty() && self.checksum() != self.expected {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                ChecksumMismatch {
+                    expected: self.expected,
+                    actual: self.checksum(),
+                },
+            ));
+        }
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crc32;
+    use rand::prelude::*;
+    use std::vec::Vec;
+
+    /// writer that accepts at most 7 bytes per call
+    struct ShortWriter(Vec<u8>);
+
+    impl Write for ShortWriter {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            let n = buf.len().min(7);
+            self.0.extend_from_slice(&buf[..n]);
+            Ok(n)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    fn random_bytes() -> Vec<u8> {
+        let mut buf = std::vec![0u8; 4096];
+        thread_rng().fill(&mut buf[..]);
+        buf
+    }
+
+    #[test]
+    fn writer_checksums_accepted_bytes() {
+        let buf = random_bytes();
+        let mut writer = Crc32Writer::new(ShortWriter(Vec::new()));
+
+        assert_eq!(writer.write(&buf).unwrap(), 7);
+        assert_eq!(writer.checksum(), crc32(&buf[..7]));
+
+        writer.write_all(&buf[7..]).unwrap();
+        let (inner, cksum) = writer.into_parts();
+        assert_eq!(inner.0, buf);
+        assert_eq!(cksum, crc32(&buf));
+    }
+
+    #[test]
+    fn reader_checksums_bytes_read() {
+        let buf = random_bytes();
+        let mut reader = Crc32Reader::new(&buf[..]);
+        let mut out = Vec::new();
+        reader.read_to_end(&mut out).unwrap();
+        assert_eq!(out, buf);
+        assert_eq!(reader.checksum(), crc32(&buf));
+    }
+
+    #[test]
+    fn verifying_reader_checks_at_eof() {
+        let buf = random_bytes();
+
+        let mut reader = Crc32VerifyingReader::new(&buf[..], crc32(&buf));
+        assert!(io::copy(&mut reader, &mut io::sink()).is_ok());
+
+        let mut corrupted = buf.clone();
+        corrupted[1234] ^= 0x10;
+        let mut reader = Crc32VerifyingReader::new(&corrupted[..], crc32(&buf));
+        let mut chunk = [0u8; 4096];
+        assert_eq!(reader.read(&mut chunk).unwrap(), 4096);
+        let err = reader.read(&mut chunk).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        let mismatch = err.get_ref().unwrap().downcast_ref::<ChecksumMismatch>();
+        assert_eq!(
+            mismatch,
+            Some(&ChecksumMismatch {
+                expected: crc32(&buf),
+                actual: crc32(&corrupted),
+            })
+        );
+    }
+}
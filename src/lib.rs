@@ -10,43 +10,94 @@
 //! ```
 #![no_std]
 
-/// used to generate up a [u32; 256] lookup table in `crc32`. this computes
-/// the table on demand for a given "index" `i`
-#[rustfmt::skip]
-const fn table_fn(i: u32) -> u32 {
-    let mut out = i;
-
-    out = if out & 1 == 1 { 0xedb88320 ^ (out >> 1) } else { out >> 1 };
-    out = if out & 1 == 1 { 0xedb88320 ^ (out >> 1) } else { out >> 1 };
-    out = if out & 1 == 1 { 0xedb88320 ^ (out >> 1) } else { out >> 1 };
-    out = if out & 1 == 1 { 0xedb88320 ^ (out >> 1) } else { out >> 1 };
-    out = if out & 1 == 1 { 0xedb88320 ^ (out >> 1) } else { out >> 1 };
-    out = if out & 1 == 1 { 0xedb88320 ^ (out >> 1) } else { out >> 1 };
-    out = if out & 1 == 1 { 0xedb88320 ^ (out >> 1) } else { out >> 1 };
-    out = if out & 1 == 1 { 0xedb88320 ^ (out >> 1) } else { out >> 1 };
-
-    out
-}
-
-const fn get_table() -> [u32; 256] {
-    let mut table: [u32; 256] = [0u32; 256];
-    let mut i = 0;
+#[cfg(feature = "std")]
+extern crate std;
 
-    while i < 256 {
-        table[i] = table_fn(i as u32);
-        i += 1;
-    }
+mod crc16;
+mod crc64;
+#[cfg(feature = "digest")]
+mod digest_impl;
+mod engine;
+mod gf2;
+mod hasher;
+mod narrow;
+mod reverse;
+mod slice;
+mod verify;
 
-    table
-}
+#[cfg(any(feature = "futures-io", feature = "tokio"))]
+pub mod async_io;
+pub mod catalog;
+pub mod correct;
+#[cfg(feature = "std")]
+pub mod io;
+pub mod phf;
+#[cfg(feature = "std")]
+pub mod runtime;
+pub mod switch;
+pub mod trailer;
+
+pub use crc16::{
+    crc16_arc, crc16_arc_seed, crc16_ccitt_false, crc16_ccitt_false_seed, crc16_kermit,
+    crc16_kermit_seed, crc16_mcrf4xx, crc16_mcrf4xx_seed, crc16_modbus, crc16_modbus_seed,
+    crc16_usb, crc16_usb_seed, crc16_x25, crc16_x25_seed, crc16_xmodem, crc16_xmodem_seed,
+};
+pub use crc64::{
+    crc64_ecma_182, crc64_ecma_182_seed, crc64_go_iso, crc64_go_iso_seed, crc64_nvme,
+    crc64_nvme_seed, crc64_xz, crc64_xz_seed,
+};
+pub use engine::{Algorithm, Crc};
+pub use hasher::{BuildCrc32, BuildCrc32c, Crc32, Crc32c};
+pub use narrow::{
+    crc10_atm, crc10_atm_seed, crc11_flexray, crc11_flexray_seed, crc12_dect, crc12_dect_seed,
+    crc15_can, crc15_can_seed, crc3_gsm, crc3_gsm_seed, crc3_rohc, crc3_rohc_seed, crc4_g_704,
+    crc4_g_704_seed, crc5_epc_c1g2, crc5_epc_c1g2_seed, crc5_usb, crc5_usb_seed, crc6_g_704,
+    crc6_g_704_seed, crc7_mmc, crc7_mmc_seed, crc8_maxim, crc8_maxim_seed, crc8_smbus,
+    crc8_smbus_seed,
+};
+pub use verify::{
+    verify, verify_be, verify_frame_be, verify_frame_le, verify_le, ChecksumMismatch,
+};
+
+/// CRC-32/ISO-HDLC, with its [u32; 256] lookup table computed at compile time
+const IEEE: Crc<u32> = Crc::<u32>::new(catalog::CRC_32_ISO_HDLC);
+
+/// reflected CRC-32/ISO-HDLC polynomial
+const IEEE_POLY: u32 = 0xedb88320;
+
+/// inverse of the top byte of the CRC-32/ISO-HDLC lookup table, for running it backwards
+const IEEE_INVERSE: [u8; 256] = reverse::inverse_table(&IEEE);
+
+/// slice-by-8 lookup tables for CRC-32/ISO-HDLC
+const IEEE_SLICE8: [[u32; 256]; 8] = slice::tables::<8>(&IEEE);
+
+/// slice-by-16 lookup tables for CRC-32/ISO-HDLC
+const IEEE_SLICE16: [[u32; 256]; 16] = slice::tables::<16>(&IEEE);
 
-const TABLE: [u32; 256] = get_table();
+/// CRC-32/ISCSI, with its [u32; 256] lookup table computed at compile time
+const CASTAGNOLI: Crc<u32> = Crc::<u32>::new(catalog::CRC_32_ISCSI);
+
+/// reflected CRC-32/ISCSI polynomial
+const CASTAGNOLI_POLY: u32 = 0x82f63b78;
+
+/// slice-by-16 lookup tables for CRC-32/ISCSI
+const CASTAGNOLI_SLICE16: [[u32; 256]; 16] = slice::tables::<16>(&CASTAGNOLI);
+
+/// CRC-32/BZIP2, with its MSB-first [u32; 256] lookup table computed at compile time
+const BZIP2: Crc<u32> = Crc::<u32>::new(catalog::CRC_32_BZIP2);
+
+/// CRC-32/MPEG-2, with its MSB-first [u32; 256] lookup table computed at compile time
+const MPEG_2: Crc<u32> = Crc::<u32>::new(catalog::CRC_32_MPEG_2);
+
+/// CRC-32/CKSUM, with its MSB-first [u32; 256] lookup table computed at compile time
+const POSIX_CKSUM: Crc<u32> = Crc::<u32>::new(catalog::CRC_32_CKSUM);
 
 /// A `const fn` crc32 checksum implementation.
 ///
-/// Note: this is a naive implementation that should be expected to have poor performance
-/// if used on dynamic data at runtime. Usage should generally be restricted to declaring
-/// `const` variables based on `static` or `const` data available at build time.
+/// Note: this is a table-driven implementation (slice-by-16) that should be expected to have
+/// poor performance compared to SIMD implementations if used on dynamic data at runtime. Usage
+/// should generally be restricted to declaring `const` variables based on `static` or `const`
+/// data available at build time.
 pub const fn crc32(buf: &[u8]) -> u32 {
     crc32_seed(buf, 0)
 }
@@ -91,13 +142,352 @@ pub const fn crc32(buf: &[u8]) -> u32 {
 /// ```
 #[inline]
 pub const fn crc32_seed(buf: &[u8], seed: u32) -> u32 {
-    let mut out = !seed;
-    let mut i = 0usize;
-    while i < buf.len() {
-        out = (out >> 8) ^ TABLE[((out & 0xff) ^ (buf[i] as u32)) as usize];
+    crc32_seed_slice16(buf, seed)
+}
+
+/// Calculate crc32 checksum from `seed` using slice-by-8 lookups, consuming 8 bytes per loop
+/// iteration with 8KB of tables.
+///
+/// Gives the same result as [`crc32_seed`]; useful where the 16KB of tables used by
+/// [`crc32_seed_slice16`] (and [`crc32_seed`]) are too much for the target.
+#[inline]
+pub const fn crc32_seed_slice8(buf: &[u8], seed: u32) -> u32 {
+    IEEE.finalize(slice::update_slice8(&IEEE_SLICE8, IEEE.resume(seed), buf))
+}
+
+/// Calculate crc32 checksum from `seed` using slice-by-16 lookups, consuming 16 bytes per loop
+/// iteration with 16KB of tables. This is what [`crc32_seed`] uses.
+#[inline]
+pub const fn crc32_seed_slice16(buf: &[u8], seed: u32) -> u32 {
+    IEEE.finalize(slice::update_slice16(&IEEE_SLICE16, IEEE.resume(seed), buf))
+}
+
+/// Combine the crc32 checksums of two buffers into the checksum of their concatenation.
+///
+/// Given `crc_a = crc32(a)`, `crc_b = crc32(b)` and `len_b = b.len()`, returns `crc32(a || b)`
+/// without access to the data, in O(log(len_b)) time. This has the same semantics as zlib's
+/// `crc32_combine`, and allows checksumming chunks of a buffer in parallel.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+///
+/// const BYTES: &[u8] = "The quick brown fox jumps over the lazy dog".as_bytes();
+/// const A: u32 = const_crc32::crc32(BYTES.split_at(10).0);
+/// const B: u32 = const_crc32::crc32(BYTES.split_at(10).1);
+///
+/// const CKSUM: u32 = const_crc32::crc32_combine(A, B, BYTES.len() as u64 - 10);
+/// assert_eq!(CKSUM, const_crc32::crc32(BYTES));
+/// ```
+pub const fn crc32_combine(crc_a: u32, crc_b: u32, len_b: u64) -> u32 {
+    gf2::multmodp(IEEE_POLY, gf2::xpow8n(IEEE_POLY, len_b), crc_a) ^ crc_b
+}
+
+/// Update the crc32 checksum of a buffer after overwriting some of its bytes.
+///
+/// `old_crc` is the checksum of a buffer of `total_len` bytes, in which `old_bytes` at
+/// `offset` have been replaced with `new_bytes`. Since crc32 is linear, the checksum changes
+/// by the checksum of the difference, so only the changed range is read, in
+/// O(`new_bytes.len()` + log(`total_len`)) time.
+///
+/// # Panics
+///
+/// Panics if `old_bytes` and `new_bytes` differ in length, or if the range extends past
+/// `total_len`.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+///
+/// let mut record = *b"The quick brown fox jumps over the lazy dog";
+/// let old_crc = const_crc32::crc32(&record);
+///
+/// record[16..19].copy_from_slice(b"cat");
+/// let new_crc =
+///     const_crc32::crc32_update_range(old_crc, record.len() as u64, 16, b"fox", b"cat");
+///
+/// assert_eq!(new_crc, const_crc32::crc32(&record));
+/// ```
+pub const fn crc32_update_range(
+    old_crc: u32,
+    total_len: u64,
+    offset: u64,
+    old_bytes: &[u8],
+    new_bytes: &[u8],
+) -> u32 {
+    assert!(old_bytes.len() == new_bytes.len());
+    let len = old_bytes.len() as u64;
+    assert!(offset <= total_len && total_len - offset >= len);
+
+    // register contribution of the difference, with no initial value or final xor
+    let mut delta = 0u32;
+    let mut i = 0;
+    while i < old_bytes.len() {
+        delta = IEEE.update(delta, &[old_bytes[i] ^ new_bytes[i]]);
         i += 1;
     }
-    !out
+
+    let trailing = total_len - offset - len;
+    old_crc ^ gf2::multmodp(IEEE_POLY, gf2::xpow8n(IEEE_POLY, trailing), delta)
+}
+
+/// Append `n` zero bytes to the data checksummed by `crc`, in O(log(n)) time.
+///
+/// Equivalent to `crc32_seed(&[0; n], crc)`, but without touching the bytes, which makes
+/// checksumming sparse files and zero-filled regions cheap.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+///
+/// const CKSUM: u32 = const_crc32::crc32_zeros(const_crc32::crc32(b"header"), 1 << 30);
+///
+/// let small = const_crc32::crc32_zeros(0, 1000);
+/// assert_eq!(small, const_crc32::crc32(&[0; 1000]));
+/// ```
+pub const fn crc32_zeros(crc: u32, n: u64) -> u32 {
+    IEEE.finalize(gf2::multmodp(
+        IEEE_POLY,
+        gf2::xpow8n(IEEE_POLY, n),
+        IEEE.resume(crc),
+    ))
+}
+
+/// Append `n` copies of `byte` to the data checksummed by `crc`, in O(log(n)) time.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+///
+/// // a 4MB erased flash sector
+/// const ERASED: u32 = const_crc32::crc32_repeat(0, 0xff, 4 << 20);
+///
+/// let small = const_crc32::crc32_repeat(0, 0xff, 1000);
+/// assert_eq!(small, const_crc32::crc32(&[0xff; 1000]));
+/// ```
+pub const fn crc32_repeat(crc: u32, byte: u8, n: u64) -> u32 {
+    crc32_repeat_pattern(crc, &[byte], n)
+}
+
+/// Append `n` copies of `pattern` to the data checksummed by `crc`, in O(len(pattern) +
+/// log(n)) time.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+///
+/// let cksum = const_crc32::crc32_repeat_pattern(const_crc32::crc32(b"fill: "), b"\xde\xad", 3);
+/// assert_eq!(cksum, const_crc32::crc32(b"fill: \xde\xad\xde\xad\xde\xad"));
+/// ```
+pub const fn crc32_repeat_pattern(crc: u32, pattern: &[u8], n: u64) -> u32 {
+    let factor = gf2::xpow8n(IEEE_POLY, pattern.len() as u64);
+    let term = IEEE.update(0, pattern);
+    IEEE.finalize(gf2::repeat(IEEE_POLY, IEEE.resume(crc), factor, term, n))
+}
+
+/// Remove `trailing_bytes` from the end of the data checksummed by `crc`.
+///
+/// Given `crc = crc32(a || b)`, `crc32_unextend(crc, b)` returns `crc32(a)` by running the
+/// register backwards over `b`, without access to `a`. This is the inverse of
+/// [`crc32_seed`], and takes time proportional to the length of `trailing_bytes`.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+///
+/// const BYTES: &[u8] = "The quick brown fox jumps over the lazy dog".as_bytes();
+/// const HEAD: &[u8] = BYTES.split_at(10).0;
+/// const TAIL: &[u8] = BYTES.split_at(10).1;
+///
+/// const CKSUM: u32 = const_crc32::crc32_unextend(const_crc32::crc32(BYTES), TAIL);
+/// assert_eq!(CKSUM, const_crc32::crc32(HEAD));
+/// ```
+pub const fn crc32_unextend(crc: u32, trailing_bytes: &[u8]) -> u32 {
+    IEEE.finalize(reverse::unupdate(
+        &IEEE,
+        &IEEE_INVERSE,
+        IEEE.resume(crc),
+        trailing_bytes,
+    ))
+}
+
+/// Compute the 4 bytes to write at `buf[offset..offset + 4]` so that the crc32 of `buf`
+/// becomes `target`.
+///
+/// The bytes currently at that position are ignored; everything else in `buf` is kept as is.
+/// Any target is reachable, so this always succeeds.
+///
+/// # Panics
+///
+/// Panics if `offset + 4` exceeds the length of `buf`.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+///
+/// let mut image = *b"firmware: ????, version 1.2.3";
+/// let patch = const_crc32::crc32_forge(&image, 10, 0xdeadbeef);
+/// image[10..14].copy_from_slice(&patch);
+///
+/// assert_eq!(const_crc32::crc32(&image), 0xdeadbeef);
+/// ```
+pub const fn crc32_forge(buf: &[u8], offset: usize, target: u32) -> [u8; 4] {
+    assert!(offset <= buf.len() && buf.len() - offset >= 4);
+
+    let (prefix, rest) = buf.split_at(offset);
+    let (_, suffix) = rest.split_at(4);
+    let from = slice::update_slice16(&IEEE_SLICE16, IEEE.init(), prefix);
+    let to = reverse::unupdate(&IEEE, &IEEE_INVERSE, IEEE.resume(target), suffix);
+    reverse::solve(&IEEE, &IEEE_INVERSE, from, to)
+}
+
+/// Compute the 4 bytes to append to `buf` so that the crc32 of the result is `target`.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+///
+/// const BYTES: &[u8] = "The quick brown fox jumps over the lazy dog".as_bytes();
+/// const PATCH: [u8; 4] = const_crc32::crc32_forge_append(BYTES, 0x12345678);
+///
+/// let cksum = const_crc32::crc32_seed(&PATCH, const_crc32::crc32(BYTES));
+/// assert_eq!(cksum, 0x12345678);
+/// ```
+pub const fn crc32_forge_append(buf: &[u8], target: u32) -> [u8; 4] {
+    let from = slice::update_slice16(&IEEE_SLICE16, IEEE.init(), buf);
+    reverse::solve(&IEEE, &IEEE_INVERSE, from, IEEE.resume(target))
+}
+
+/// A `const fn` crc32c (Castagnoli) checksum implementation.
+///
+/// This is the CRC-32 variant used by iSCSI, ext4 and Kafka record batches. The same
+/// performance caveats as [`crc32`] apply.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// const CKSUM: u32 = const_crc32::crc32c(b"123456789");
+/// assert_eq!(CKSUM, 0xe3069283_u32);
+/// ```
+pub const fn crc32c(buf: &[u8]) -> u32 {
+    crc32c_seed(buf, 0)
+}
+
+/// Calculate crc32c checksum, using provided `seed` as the initial state, instead of the
+/// default initial state of `0u32`. Seeds behave the same way as in [`crc32_seed`].
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+///
+/// const BYTES: &[u8] = "The quick brown fox jumps over the lazy dog".as_bytes();
+///
+/// let cksum = const_crc32::crc32c_seed(&BYTES[10..], const_crc32::crc32c(&BYTES[..10]));
+///
+/// assert_eq!(cksum, const_crc32::crc32c(BYTES));
+/// ```
+#[inline]
+pub const fn crc32c_seed(buf: &[u8], seed: u32) -> u32 {
+    CASTAGNOLI.finalize(slice::update_slice16(
+        &CASTAGNOLI_SLICE16,
+        CASTAGNOLI.resume(seed),
+        buf,
+    ))
+}
+
+/// A `const fn` CRC-32/BZIP2 checksum implementation.
+///
+/// This is the unreflected (MSB-first) counterpart of [`crc32`], using the same polynomial.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// const CKSUM: u32 = const_crc32::crc32_bzip2(b"123456789");
+/// assert_eq!(CKSUM, 0xfc891918_u32);
+/// ```
+pub const fn crc32_bzip2(buf: &[u8]) -> u32 {
+    crc32_bzip2_seed(buf, 0)
+}
+
+/// Calculate CRC-32/BZIP2 checksum, using provided `seed` as the initial state. Seeds behave
+/// the same way as in [`crc32_seed`].
+#[inline]
+pub const fn crc32_bzip2_seed(buf: &[u8], seed: u32) -> u32 {
+    BZIP2.checksum_seed(buf, seed)
+}
+
+/// A `const fn` CRC-32/MPEG-2 checksum implementation.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// const CKSUM: u32 = const_crc32::crc32_mpeg2(b"123456789");
+/// assert_eq!(CKSUM, 0x0376e6e7_u32);
+/// ```
+pub const fn crc32_mpeg2(buf: &[u8]) -> u32 {
+    MPEG_2.checksum(buf)
+}
+
+/// Calculate CRC-32/MPEG-2 checksum, continuing from `seed`, the checksum of the preceding
+/// data.
+///
+/// Unlike [`crc32_seed`], the starting state of CRC-32/MPEG-2 is not reached with a seed of
+/// `0u32`; start from [`crc32_mpeg2`] instead:
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+///
+/// const BYTES: &[u8] = "The quick brown fox jumps over the lazy dog".as_bytes();
+///
+/// let cksum = const_crc32::crc32_mpeg2(&BYTES[..10]);
+/// let cksum = const_crc32::crc32_mpeg2_seed(&BYTES[10..], cksum);
+///
+/// assert_eq!(cksum, const_crc32::crc32_mpeg2(BYTES));
+/// ```
+#[inline]
+pub const fn crc32_mpeg2_seed(buf: &[u8], seed: u32) -> u32 {
+    MPEG_2.checksum_seed(buf, seed)
+}
+
+/// A `const fn` implementation of the checksum printed by the POSIX `cksum` utility.
+///
+/// After the data, the length of `buf` is fed through the register as little-endian bytes,
+/// omitting the most significant zero bytes, so the result matches `cksum` exactly.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// // printf 123456789 | cksum
+/// const CKSUM: u32 = const_crc32::crc32_cksum(b"123456789");
+/// assert_eq!(CKSUM, 930766865_u32);
+/// ```
+pub const fn crc32_cksum(buf: &[u8]) -> u32 {
+    let mut crc = POSIX_CKSUM.update(POSIX_CKSUM.init(), buf);
+    let mut len = buf.len() as u64;
+    while len != 0 {
+        crc = POSIX_CKSUM.update(crc, &[len as u8]);
+        len >>= 8;
+    }
+    POSIX_CKSUM.finalize(crc)
+}
+
+/// Combine the crc32c checksums of two buffers into the checksum of their concatenation.
+/// See [`crc32_combine`].
+pub const fn crc32c_combine(crc_a: u32, crc_b: u32, len_b: u64) -> u32 {
+    gf2::multmodp(CASTAGNOLI_POLY, gf2::xpow8n(CASTAGNOLI_POLY, len_b), crc_a) ^ crc_b
 }
 
 #[cfg(test)]
@@ -119,11 +509,109 @@ mod tests {
     }
 
     #[test]
-    fn check_table_fn_against_example_code() {
-        let table = crc32_compute_table();
-        for i in 0..256 {
-            assert_eq!(table[i], table_fn(i as u32));
+    fn check_table_against_example_code() {
+        assert_eq!(&crc32_compute_table(), IEEE.table());
+    }
+
+    fn crc32c_compute_table() -> [u32; 256] {
+        let mut crc32c_table = [0; 256];
+
+        for n in 0..256_u32 {
+            crc32c_table[n as usize] = (0..8).fold(n, |acc, _| match acc & 1 {
+                1 => 0x82f63b78 ^ (acc >> 1),
+                _ => acc >> 1,
+            });
+        }
+
+        crc32c_table
+    }
+
+    #[test]
+    fn check_crc32c_table_against_example_code() {
+        assert_eq!(&crc32c_compute_table(), CASTAGNOLI.table());
+    }
+
+    #[test]
+    fn crc32c_check_value() {
+        const CKSUM: u32 = crc32c(b"123456789");
+        assert_eq!(CKSUM, 0xe3069283_u32);
+        // RFC 3720, B.4: 32 bytes of zeros
+        assert_eq!(crc32c(&[0u8; 32]), 0x8a9136aa_u32);
+    }
+
+    #[test]
+    fn use_seed_to_crc32c_from_many_chunks() {
+        let mut buf = [0u8; 1024];
+        let mut rng = thread_rng();
+        rng.fill(&mut buf[..]);
+
+        let mut cksum = 0;
+        for chunk in buf[..].chunks(7) {
+            cksum = crc32c_seed(chunk, cksum);
+        }
+
+        assert_eq!(cksum, crc32c(&buf[..]));
+    }
+
+    fn crc32_msb_first_compute_table() -> [u32; 256] {
+        let mut crc32_table = [0; 256];
+
+        for n in 0..256_u32 {
+            crc32_table[n as usize] = (0..8).fold(n << 24, |acc, _| match acc >> 31 {
+                1 => 0x04c11db7 ^ (acc << 1),
+                _ => acc << 1,
+            });
+        }
+
+        crc32_table
+    }
+
+    #[test]
+    fn check_msb_first_tables_against_example_code() {
+        let table = crc32_msb_first_compute_table();
+        assert_eq!(&table, BZIP2.table());
+        assert_eq!(&table, MPEG_2.table());
+        assert_eq!(&table, POSIX_CKSUM.table());
+    }
+
+    #[test]
+    fn msb_first_check_values() {
+        assert_eq!(crc32_bzip2(b"123456789"), 0xfc891918_u32);
+        assert_eq!(crc32_mpeg2(b"123456789"), 0x0376e6e7_u32);
+        assert_eq!(POSIX_CKSUM.checksum(b"123456789"), 0x765e7680_u32);
+    }
+
+    #[test]
+    fn crc32_cksum_matches_posix_cksum() {
+        // expected values are the output of the `cksum` utility
+        const BYTES: &[u8] = "The quick brown fox jumps over the lazy dog".as_bytes();
+        assert_eq!(crc32_cksum(b""), 4294967295_u32);
+        assert_eq!(crc32_cksum(b"123456789"), 930766865_u32);
+        assert_eq!(crc32_cksum(BYTES), 2074844392_u32);
+        assert_eq!(crc32_cksum(&[b'*'; 5000]), 3661298763_u32);
+
+        let mut buf = [0u8; 512];
+        for (i, b) in buf.iter_mut().enumerate() {
+            *b = i as u8;
+        }
+        assert_eq!(crc32_cksum(&buf), 3765074165_u32);
+    }
+
+    #[test]
+    fn use_seed_to_bzip2_and_mpeg2_from_many_chunks() {
+        let mut buf = [0u8; 1024];
+        let mut rng = thread_rng();
+        rng.fill(&mut buf[..]);
+
+        let mut bzip2 = crc32_bzip2(&[]);
+        let mut mpeg2 = crc32_mpeg2(&[]);
+        for chunk in buf[..].chunks(7) {
+            bzip2 = crc32_bzip2_seed(chunk, bzip2);
+            mpeg2 = crc32_mpeg2_seed(chunk, mpeg2);
         }
+
+        assert_eq!(bzip2, crc32_bzip2(&buf[..]));
+        assert_eq!(mpeg2, crc32_mpeg2(&buf[..]));
     }
 
     #[test]
@@ -173,13 +661,192 @@ mod tests {
     fn check_const_eval_limit_not_reached_on_100k_data() {
         const BYTES: &[u8] = &[42u8; 1024 * 100];
         const CKSUM: u32 = crc32(BYTES);
-        assert_eq!(CKSUM, crc32fast::hash(&BYTES[..]));
+        assert_eq!(CKSUM, crc32fast::hash(BYTES));
+    }
+
+    #[test]
+    fn check_const_eval_limit_not_reached_on_1mb_data() {
+        const BYTES: &[u8] = &[42u8; 1024 * 1024];
+        const CKSUM: u32 = crc32(BYTES);
+        assert_eq!(CKSUM, crc32fast::hash(BYTES));
+    }
+
+    #[test]
+    fn combine_matches_checksum_of_concatenation() {
+        let mut buf = [0u8; 4096];
+        let mut rng = thread_rng();
+        rng.fill(&mut buf[..]);
+
+        for _ in 0..100 {
+            let mid = rng.gen_range(0..=buf.len());
+            let (a, b) = buf.split_at(mid);
+            let len_b = b.len() as u64;
+            assert_eq!(crc32_combine(crc32(a), crc32(b), len_b), crc32(&buf));
+            assert_eq!(crc32c_combine(crc32c(a), crc32c(b), len_b), crc32c(&buf));
+        }
+    }
+
+    #[test]
+    fn combine_matches_crc32_fast() {
+        let mut a = crc32fast::Hasher::new();
+        a.update(b"hello ");
+        let mut b = crc32fast::Hasher::new();
+        b.update(b"world");
+        let expected = crc32(b"hello world");
+
+        a.combine(&b);
+        assert_eq!(a.finalize(), expected);
+        assert_eq!(
+            crc32_combine(crc32(b"hello "), crc32(b"world"), 5),
+            expected
+        );
+        assert_eq!(crc32_combine(expected, crc32(&[]), 0), expected);
+    }
+
+    #[test]
+    fn combine_by_repeated_doubling() {
+        static ZEROS: [u8; 1 << 20] = [0u8; 1 << 20];
+
+        let mut cksum = crc32(&ZEROS[..16]);
+        let mut len = 16u64;
+        while len < ZEROS.len() as u64 {
+            cksum = crc32_combine(cksum, cksum, len);
+            len *= 2;
+        }
+
+        assert_eq!(cksum, crc32(&ZEROS));
+    }
+
+    #[test]
+    fn slice8_and_slice16_match_crc32_fast() {
+        let mut buf = [0u8; 4096];
+        let mut rng = thread_rng();
+        rng.fill(&mut buf[..]);
+
+        for len in [0, 1, 7, 8, 9, 15, 16, 17, 100, 4095, 4096] {
+            let expected = crc32fast::hash(&buf[..len]);
+            assert_eq!(crc32_seed_slice8(&buf[..len], 0), expected);
+            assert_eq!(crc32_seed_slice16(&buf[..len], 0), expected);
+        }
+    }
+
+    #[test]
+    fn forge_at_every_offset() {
+        let mut buf = [0u8; 40];
+        let mut rng = thread_rng();
+        rng.fill(&mut buf[..]);
+
+        for offset in 0..=buf.len() - 4 {
+            let target: u32 = rng.gen();
+            let patch = crc32_forge(&buf, offset, target);
+            let mut patched = buf;
+            patched[offset..offset + 4].copy_from_slice(&patch);
+            assert_eq!(crc32(&patched), target, "offset {}", offset);
+        }
+    }
+
+    #[test]
+    fn forge_append_reaches_any_target() {
+        let mut buf = [0u8; 40];
+        let mut rng = thread_rng();
+        rng.fill(&mut buf[..]);
+
+        for len in [0, 1, 7, 40] {
+            for target in [0, u32::MAX, rng.gen()] {
+                let patch = crc32_forge_append(&buf[..len], target);
+                assert_eq!(crc32_seed(&patch, crc32(&buf[..len])), target);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn forge_past_the_end_panics() {
+        crc32_forge(b"abcdef", 3, 0);
+    }
+
+    #[test]
+    fn unextend_inverts_seed() {
+        let mut buf = [0u8; 300];
+        rand::thread_rng().fill(&mut buf[..]);
+        let cksum = crc32(&buf);
+
+        for split in [0, 1, 17, 299, 300] {
+            assert_eq!(crc32_unextend(cksum, &buf[split..]), crc32(&buf[..split]));
+        }
+        assert_eq!(crc32_unextend(cksum, &buf), 0);
     }
 
-    // #[test]
-    // fn check_const_eval_limit_not_reached_on_1mb_data() {
-    //     const BYTES: &[u8] = &[42u8; 1024 * 1024];
-    //     const CKSUM: u32 = crc32(BYTES);
-    //     assert_eq!(CKSUM, crc32fast::hash(&BYTES[..]));
-    // }
+    #[test]
+    fn update_range_matches_checksum_of_modified_buffer() {
+        let mut buf = [0u8; 500];
+        let mut rng = thread_rng();
+        rng.fill(&mut buf[..]);
+
+        for _ in 0..50 {
+            let old_crc = crc32(&buf);
+            let len = rng.gen_range(0..=32);
+            let offset = rng.gen_range(0..=buf.len() - len);
+            let old = buf;
+            rng.fill(&mut buf[offset..offset + len]);
+
+            let new_crc = crc32_update_range(
+                old_crc,
+                buf.len() as u64,
+                offset as u64,
+                &old[offset..offset + len],
+                &buf[offset..offset + len],
+            );
+            assert_eq!(new_crc, crc32(&buf));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn update_range_past_the_end_panics() {
+        crc32_update_range(0, 4, 2, b"abc", b"def");
+    }
+
+    #[test]
+    fn zeros_and_repeats_match_checksum_of_expanded_data() {
+        let head = crc32(b"The quick brown fox");
+        let mut buf = [0u8; 1000];
+
+        for n in [0, 1, 2, 15, 16, 17, 999] {
+            assert_eq!(crc32_zeros(head, n as u64), crc32_seed(&buf[..n], head));
+        }
+
+        for byte in [0x00, 0x5a, 0xff] {
+            buf.fill(byte);
+            for n in [0, 1, 255, 1000] {
+                assert_eq!(
+                    crc32_repeat(head, byte, n as u64),
+                    crc32_seed(&buf[..n], head)
+                );
+            }
+        }
+
+        let pattern = b"abcdefg";
+        for n in 0..100 {
+            let expanded = &mut buf[..pattern.len() * n];
+            for chunk in expanded.chunks_mut(pattern.len()) {
+                chunk.copy_from_slice(pattern);
+            }
+            assert_eq!(
+                crc32_repeat_pattern(head, pattern, n as u64),
+                crc32_seed(expanded, head)
+            );
+        }
+    }
+
+    #[test]
+    fn zeros_agree_with_combine() {
+        let zeros_1k = crc32(&[0; 1024]);
+        let mut crc = 0;
+        for _ in 0..64 {
+            crc = crc32_combine(crc, zeros_1k, 1024);
+        }
+        assert_eq!(crc32_zeros(0, 64 * 1024), crc);
+        assert_eq!(crc32_repeat_pattern(0, &[], 1 << 40), 0);
+    }
 }
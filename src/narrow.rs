@@ -0,0 +1,318 @@
+//! `const fn` checksums for CRCs narrower than 16 bits.
+//!
+//! Widths up to 8 bits use a [u8; 256] lookup table and wider ones a [u16; 256] table, both
+//! computed at compile time. The register of an unreflected CRC is kept in the most significant
+//! bits of the table type, so that CRCs narrower than a byte still consume a whole byte per
+//! table lookup; see [`Crc`]. The `_seed` functions continue from `seed`, the checksum of the
+//! preceding data, so that `crc5_usb_seed(b, crc5_usb(a))` is the checksum of `a` followed by
+//! `b`. For the variants whose `init` differs from their `xorout` (CRC-3/GSM, CRC-3/ROHC,
+//! CRC-5/EPC-C1G2 and CRC-11/FLEXRAY) a seed of `0` is *not* the same as starting fresh; use the
+//! unseeded function for the first part.
+
+use crate::{catalog, Crc};
+
+const GSM_3: Crc<u8> = Crc::<u8>::new(catalog::CRC_3_GSM);
+const ROHC_3: Crc<u8> = Crc::<u8>::new(catalog::CRC_3_ROHC);
+const G_704_4: Crc<u8> = Crc::<u8>::new(catalog::CRC_4_G_704);
+const USB_5: Crc<u8> = Crc::<u8>::new(catalog::CRC_5_USB);
+const EPC_C1G2_5: Crc<u8> = Crc::<u8>::new(catalog::CRC_5_EPC_C1G2);
+const G_704_6: Crc<u8> = Crc::<u8>::new(catalog::CRC_6_G_704);
+const MMC_7: Crc<u8> = Crc::<u8>::new(catalog::CRC_7_MMC);
+const SMBUS_8: Crc<u8> = Crc::<u8>::new(catalog::CRC_8_SMBUS);
+const MAXIM_DOW_8: Crc<u8> = Crc::<u8>::new(catalog::CRC_8_MAXIM_DOW);
+const ATM_10: Crc<u16> = Crc::<u16>::new(catalog::CRC_10_ATM);
+const FLEXRAY_11: Crc<u16> = Crc::<u16>::new(catalog::CRC_11_FLEXRAY);
+const DECT_12: Crc<u16> = Crc::<u16>::new(catalog::CRC_12_DECT);
+const CAN_15: Crc<u16> = Crc::<u16>::new(catalog::CRC_15_CAN);
+
+/// A `const fn` CRC-3/GSM checksum implementation.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// const CKSUM: u8 = const_crc32::crc3_gsm(b"123456789");
+/// assert_eq!(CKSUM, 0x4_u8);
+/// ```
+pub const fn crc3_gsm(buf: &[u8]) -> u8 {
+    GSM_3.checksum(buf)
+}
+
+/// Calculate CRC-3/GSM checksum, continuing from `seed`, the checksum of the preceding data.
+#[inline]
+pub const fn crc3_gsm_seed(buf: &[u8], seed: u8) -> u8 {
+    GSM_3.checksum_seed(buf, seed)
+}
+
+/// A `const fn` CRC-3/ROHC checksum implementation.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// const CKSUM: u8 = const_crc32::crc3_rohc(b"123456789");
+/// assert_eq!(CKSUM, 0x6_u8);
+/// ```
+pub const fn crc3_rohc(buf: &[u8]) -> u8 {
+    ROHC_3.checksum(buf)
+}
+
+/// Calculate CRC-3/ROHC checksum, continuing from `seed`, the checksum of the preceding data.
+#[inline]
+pub const fn crc3_rohc_seed(buf: &[u8], seed: u8) -> u8 {
+    ROHC_3.checksum_seed(buf, seed)
+}
+
+/// A `const fn` CRC-4/G-704 checksum implementation.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// const CKSUM: u8 = const_crc32::crc4_g_704(b"123456789");
+/// assert_eq!(CKSUM, 0x7_u8);
+/// ```
+pub const fn crc4_g_704(buf: &[u8]) -> u8 {
+    G_704_4.checksum(buf)
+}
+
+/// Calculate CRC-4/G-704 checksum, continuing from `seed`, the checksum of the preceding data.
+#[inline]
+pub const fn crc4_g_704_seed(buf: &[u8], seed: u8) -> u8 {
+    G_704_4.checksum_seed(buf, seed)
+}
+
+/// A `const fn` CRC-5/USB checksum implementation.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// const CKSUM: u8 = const_crc32::crc5_usb(b"123456789");
+/// assert_eq!(CKSUM, 0x19_u8);
+/// ```
+pub const fn crc5_usb(buf: &[u8]) -> u8 {
+    USB_5.checksum(buf)
+}
+
+/// Calculate CRC-5/USB checksum, continuing from `seed`, the checksum of the preceding data.
+#[inline]
+pub const fn crc5_usb_seed(buf: &[u8], seed: u8) -> u8 {
+    USB_5.checksum_seed(buf, seed)
+}
+
+/// A `const fn` CRC-5/EPC-C1G2 checksum implementation.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// const CKSUM: u8 = const_crc32::crc5_epc_c1g2(b"123456789");
+/// assert_eq!(CKSUM, 0x00_u8);
+/// ```
+pub const fn crc5_epc_c1g2(buf: &[u8]) -> u8 {
+    EPC_C1G2_5.checksum(buf)
+}
+
+/// Calculate CRC-5/EPC-C1G2 checksum, continuing from `seed`, the checksum of the preceding data.
+#[inline]
+pub const fn crc5_epc_c1g2_seed(buf: &[u8], seed: u8) -> u8 {
+    EPC_C1G2_5.checksum_seed(buf, seed)
+}
+
+/// A `const fn` CRC-6/G-704 checksum implementation.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// const CKSUM: u8 = const_crc32::crc6_g_704(b"123456789");
+/// assert_eq!(CKSUM, 0x06_u8);
+/// ```
+pub const fn crc6_g_704(buf: &[u8]) -> u8 {
+    G_704_6.checksum(buf)
+}
+
+/// Calculate CRC-6/G-704 checksum, continuing from `seed`, the checksum of the preceding data.
+#[inline]
+pub const fn crc6_g_704_seed(buf: &[u8], seed: u8) -> u8 {
+    G_704_6.checksum_seed(buf, seed)
+}
+
+/// A `const fn` CRC-7/MMC checksum implementation.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// const CKSUM: u8 = const_crc32::crc7_mmc(b"123456789");
+/// assert_eq!(CKSUM, 0x75_u8);
+/// ```
+pub const fn crc7_mmc(buf: &[u8]) -> u8 {
+    MMC_7.checksum(buf)
+}
+
+/// Calculate CRC-7/MMC checksum, continuing from `seed`, the checksum of the preceding data.
+#[inline]
+pub const fn crc7_mmc_seed(buf: &[u8], seed: u8) -> u8 {
+    MMC_7.checksum_seed(buf, seed)
+}
+
+/// A `const fn` CRC-8/SMBUS checksum implementation.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// const CKSUM: u8 = const_crc32::crc8_smbus(b"123456789");
+/// assert_eq!(CKSUM, 0xf4_u8);
+/// ```
+pub const fn crc8_smbus(buf: &[u8]) -> u8 {
+    SMBUS_8.checksum(buf)
+}
+
+/// Calculate CRC-8/SMBUS checksum, continuing from `seed`, the checksum of the preceding data.
+#[inline]
+pub const fn crc8_smbus_seed(buf: &[u8], seed: u8) -> u8 {
+    SMBUS_8.checksum_seed(buf, seed)
+}
+
+/// A `const fn` CRC-8/MAXIM-DOW checksum implementation.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// const CKSUM: u8 = const_crc32::crc8_maxim(b"123456789");
+/// assert_eq!(CKSUM, 0xa1_u8);
+/// ```
+pub const fn crc8_maxim(buf: &[u8]) -> u8 {
+    MAXIM_DOW_8.checksum(buf)
+}
+
+/// Calculate CRC-8/MAXIM-DOW checksum, continuing from `seed`, the checksum of the preceding data.
+#[inline]
+pub const fn crc8_maxim_seed(buf: &[u8], seed: u8) -> u8 {
+    MAXIM_DOW_8.checksum_seed(buf, seed)
+}
+
+/// A `const fn` CRC-10/ATM checksum implementation.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// const CKSUM: u16 = const_crc32::crc10_atm(b"123456789");
+/// assert_eq!(CKSUM, 0x199_u16);
+/// ```
+pub const fn crc10_atm(buf: &[u8]) -> u16 {
+    ATM_10.checksum(buf)
+}
+
+/// Calculate CRC-10/ATM checksum, continuing from `seed`, the checksum of the preceding data.
+#[inline]
+pub const fn crc10_atm_seed(buf: &[u8], seed: u16) -> u16 {
+    ATM_10.checksum_seed(buf, seed)
+}
+
+/// A `const fn` CRC-11/FLEXRAY checksum implementation.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// const CKSUM: u16 = const_crc32::crc11_flexray(b"123456789");
+/// assert_eq!(CKSUM, 0x5a3_u16);
+/// ```
+pub const fn crc11_flexray(buf: &[u8]) -> u16 {
+    FLEXRAY_11.checksum(buf)
+}
+
+/// Calculate CRC-11/FLEXRAY checksum, continuing from `seed`, the checksum of the preceding data.
+#[inline]
+pub const fn crc11_flexray_seed(buf: &[u8], seed: u16) -> u16 {
+    FLEXRAY_11.checksum_seed(buf, seed)
+}
+
+/// A `const fn` CRC-12/DECT checksum implementation.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// const CKSUM: u16 = const_crc32::crc12_dect(b"123456789");
+/// assert_eq!(CKSUM, 0xf5b_u16);
+/// ```
+pub const fn crc12_dect(buf: &[u8]) -> u16 {
+    DECT_12.checksum(buf)
+}
+
+/// Calculate CRC-12/DECT checksum, continuing from `seed`, the checksum of the preceding data.
+#[inline]
+pub const fn crc12_dect_seed(buf: &[u8], seed: u16) -> u16 {
+    DECT_12.checksum_seed(buf, seed)
+}
+
+/// A `const fn` CRC-15/CAN checksum implementation.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+/// const CKSUM: u16 = const_crc32::crc15_can(b"123456789");
+/// assert_eq!(CKSUM, 0x059e_u16);
+/// ```
+pub const fn crc15_can(buf: &[u8]) -> u16 {
+    CAN_15.checksum(buf)
+}
+
+/// Calculate CRC-15/CAN checksum, continuing from `seed`, the checksum of the preceding data.
+#[inline]
+pub const fn crc15_can_seed(buf: &[u8], seed: u16) -> u16 {
+    CAN_15.checksum_seed(buf, seed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::*;
+
+    #[test]
+    fn check_values() {
+        assert_eq!(crc3_gsm(b"123456789"), catalog::CRC_3_GSM.check);
+        assert_eq!(crc3_rohc(b"123456789"), catalog::CRC_3_ROHC.check);
+        assert_eq!(crc4_g_704(b"123456789"), catalog::CRC_4_G_704.check);
+        assert_eq!(crc5_usb(b"123456789"), catalog::CRC_5_USB.check);
+        assert_eq!(crc5_epc_c1g2(b"123456789"), catalog::CRC_5_EPC_C1G2.check);
+        assert_eq!(crc6_g_704(b"123456789"), catalog::CRC_6_G_704.check);
+        assert_eq!(crc7_mmc(b"123456789"), catalog::CRC_7_MMC.check);
+        assert_eq!(crc8_smbus(b"123456789"), catalog::CRC_8_SMBUS.check);
+        assert_eq!(crc8_maxim(b"123456789"), catalog::CRC_8_MAXIM_DOW.check);
+        assert_eq!(crc10_atm(b"123456789"), catalog::CRC_10_ATM.check);
+        assert_eq!(crc11_flexray(b"123456789"), catalog::CRC_11_FLEXRAY.check);
+        assert_eq!(crc12_dect(b"123456789"), catalog::CRC_12_DECT.check);
+        assert_eq!(crc15_can(b"123456789"), catalog::CRC_15_CAN.check);
+    }
+
+    #[test]
+    fn use_seed_to_checksum_from_many_chunks() {
+        let mut buf = [0u8; 1024];
+        let mut rng = thread_rng();
+        rng.fill(&mut buf[..]);
+
+        let (a, b) = buf.split_at(333);
+        assert_eq!(crc3_gsm_seed(b, crc3_gsm(a)), crc3_gsm(&buf));
+        assert_eq!(crc3_rohc_seed(b, crc3_rohc(a)), crc3_rohc(&buf));
+        assert_eq!(crc4_g_704_seed(b, crc4_g_704(a)), crc4_g_704(&buf));
+        assert_eq!(crc5_usb_seed(b, crc5_usb(a)), crc5_usb(&buf));
+        assert_eq!(crc5_epc_c1g2_seed(b, crc5_epc_c1g2(a)), crc5_epc_c1g2(&buf));
+        assert_eq!(crc6_g_704_seed(b, crc6_g_704(a)), crc6_g_704(&buf));
+        assert_eq!(crc7_mmc_seed(b, crc7_mmc(a)), crc7_mmc(&buf));
+        assert_eq!(crc8_smbus_seed(b, crc8_smbus(a)), crc8_smbus(&buf));
+        assert_eq!(crc8_maxim_seed(b, crc8_maxim(a)), crc8_maxim(&buf));
+        assert_eq!(crc10_atm_seed(b, crc10_atm(a)), crc10_atm(&buf));
+        assert_eq!(crc11_flexray_seed(b, crc11_flexray(a)), crc11_flexray(&buf));
+        assert_eq!(crc12_dect_seed(b, crc12_dect(a)), crc12_dect(&buf));
+        assert_eq!(crc15_can_seed(b, crc15_can(a)), crc15_can(&buf));
+    }
+}
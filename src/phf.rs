@@ -0,0 +1,318 @@
+//! A perfect hash map from static strings, built entirely at compile time.
+//!
+//! Uses the hash-and-displace scheme: keys are split into buckets by one hash, and each bucket
+//! gets a displacement, a crc32 seed under which all of its keys land in free slots. A lookup
+//! costs two crc32 computations and one string comparison, and needs neither `std` nor a
+//! build script.
+
+use crate::crc32_seed;
+
+/// most seeds tried for a single bucket before giving up
+const MAX_DISPLACEMENT: u32 = 1 << 16;
+
+/// crc32 of `key` under `seed`, followed by a murmur3 finalizer. crc32 is affine in its seed,
+/// so without the finalizer two keys of equal length would collide under every seed once they
+/// collide under one
+const fn hash(key: &[u8], seed: u32) -> u32 {
+    let mut h = crc32_seed(key, seed);
+    h ^= h >> 16;
+    h = h.wrapping_mul(0x85ebca6b);
+    h ^= h >> 13;
+    h = h.wrapping_mul(0xc2b2ae35);
+    h ^= h >> 16;
+    h
+}
+
+const fn bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < a.len() {
+        if a[i] != b[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// An immutable map from `&'static str` keys to values of type `V`, with a perfect hash
+/// function computed at compile time.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd::phf::Crc32Map;
+///
+/// static BAUD_RATES: Crc32Map<u32, 4> = Crc32Map::new([
+///     ("slow", 9600),
+///     ("default", 115200),
+///     ("fast", 921600),
+///     ("turbo", 3000000),
+/// ]);
+///
+/// assert_eq!(BAUD_RATES.get("fast"), Some(&921600));
+/// assert_eq!(BAUD_RATES.get("warp"), None);
+/// ```
+///
+/// Duplicate keys are rejected at compile time:
+///
+/// ```compile_fail
+/// use const_crc32_nostd::phf::Crc32Map;
+///
+/// static MAP: Crc32Map<u8, 2> = Crc32Map::new([("a", 1), ("a", 2)]);
+/// ```
+#[derive(Debug)]
+pub struct Crc32Map<V, const N: usize> {
+    entries: [(&'static str, V); N],
+    /// crc32 seed of each bucket
+    displacements: [u32; N],
+    /// index into `entries` of the key stored in each slot
+    slots: [usize; N],
+}
+
+impl<V, const N: usize> Crc32Map<V, N> {
+    /// Build the map. Intended to be evaluated in a `const` or `static` initializer, where it
+    /// runs at compile time; construction is quadratic in `N`, so this is meant for tables of
+    /// up to a few hundred keys.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a key appears twice, if two keys of the same length have the same crc32, or
+    /// (very unlikely) if no displacement can be found for some bucket.
+    pub const fn new(entries: [(&'static str, V); N]) -> Self {
+        let mut i = 0;
+        while i < N {
+            let mut j = i + 1;
+            while j < N {
+                let (a, b) = (entries[i].0.as_bytes(), entries[j].0.as_bytes());
+                if bytes_eq(a, b) {
+                    panic!("duplicate key in Crc32Map");
+                }
+                // keys of equal length and crc32 land in the same slot under every seed
+                if a.len() == b.len() && crc32_seed(a, 0) == crc32_seed(b, 0) {
+                    panic!("two keys of a Crc32Map have the same crc32");
+                }
+                j += 1;
+            }
+            i += 1;
+        }
+
+        let mut bucket_of = [0usize; N];
+        let mut bucket_len = [0usize; N];
+        let mut i = 0;
+        while i < N {
+            let bucket = hash(entries[i].0.as_bytes(), 0) as usize % N;
+            bucket_of[i] = bucket;
+            bucket_len[bucket] += 1;
+            i += 1;
+        }
+
+        // place the largest buckets first, while most slots are still free
+        let mut order = [0usize; N];
+        let mut i = 0;
+        while i < N {
+            order[i] = i;
+            i += 1;
+        }
+        let mut i = 0;
+        while i < N {
+            let mut max = i;
+            let mut j = i + 1;
+            while j < N {
+                if bucket_len[order[j]] > bucket_len[order[max]] {
+                    max = j;
+                }
+                j += 1;
+            }
+            let tmp = order[i];
+            order[i] = order[max];
+            order[max] = tmp;
+            i += 1;
+        }
+
+        let mut displacements = [0u32; N];
+        let mut slots = [usize::MAX; N];
+        let mut n = 0;
+        while n < N && bucket_len[order[n]] > 0 {
+            let bucket = order[n];
+            let mut seed = 1;
+
+            'seeds: loop {
+                if seed > MAX_DISPLACEMENT {
+                    panic!("no displacement found for a Crc32Map bucket");
+                }
+
+                let mut placed = [usize::MAX; N];
+                let mut n_placed = 0;
+                let mut i = 0;
+                while i < N {
+                    if bucket_of[i] == bucket {
+                        let slot = hash(entries[i].0.as_bytes(), seed) as usize % N;
+                        let mut taken = slots[slot] != usize::MAX;
+                        let mut k = 0;
+                        while k < n_placed {
+                            taken |= placed[k] == slot;
+                            k += 1;
+                        }
+                        if taken {
+                            seed += 1;
+                            continue 'seeds;
+                        }
+                        placed[n_placed] = slot;
+                        n_placed += 1;
+                    }
+                    i += 1;
+                }
+
+                let mut i = 0;
+                let mut k = 0;
+                while i < N {
+                    if bucket_of[i] == bucket {
+                        slots[placed[k]] = i;
+                        k += 1;
+                    }
+                    i += 1;
+                }
+                displacements[bucket] = seed;
+                break;
+            }
+
+            n += 1;
+        }
+
+        Self {
+            entries,
+            displacements,
+            slots,
+        }
+    }
+
+    /// The value for `key`, if present.
+    pub const fn get(&self, key: &str) -> Option<&V> {
+        match self.get_entry(key) {
+            Some((_, value)) => Some(value),
+            None => None,
+        }
+    }
+
+    /// The stored key and value for `key`, if present.
+    pub const fn get_entry(&self, key: &str) -> Option<&(&'static str, V)> {
+        if N == 0 {
+            return None;
+        }
+        let bucket = hash(key.as_bytes(), 0) as usize % N;
+        let slot = hash(key.as_bytes(), self.displacements[bucket]) as usize % N;
+        let entry = &self.entries[self.slots[slot]];
+        if bytes_eq(entry.0.as_bytes(), key.as_bytes()) {
+            Some(entry)
+        } else {
+            None
+        }
+    }
+
+    /// Whether `key` is present.
+    pub const fn contains_key(&self, key: &str) -> bool {
+        self.get_entry(key).is_some()
+    }
+
+    /// Number of entries.
+    pub const fn len(&self) -> usize {
+        N
+    }
+
+    /// Whether the map has no entries.
+    pub const fn is_empty(&self) -> bool {
+        N == 0
+    }
+
+    /// The entries, in the order they were passed to [`new`](Self::new).
+    pub const fn entries(&self) -> &[(&'static str, V)] {
+        &self.entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEYWORDS: [(&str, usize); 40] = [
+        ("as", 0),
+        ("break", 1),
+        ("const", 2),
+        ("continue", 3),
+        ("crate", 4),
+        ("else", 5),
+        ("enum", 6),
+        ("extern", 7),
+        ("false", 8),
+        ("fn", 9),
+        ("for", 10),
+        ("if", 11),
+        ("impl", 12),
+        ("in", 13),
+        ("let", 14),
+        ("loop", 15),
+        ("match", 16),
+        ("mod", 17),
+        ("move", 18),
+        ("mut", 19),
+        ("pub", 20),
+        ("ref", 21),
+        ("return", 22),
+        ("self", 23),
+        ("Self", 24),
+        ("static", 25),
+        ("struct", 26),
+        ("super", 27),
+        ("trait", 28),
+        ("true", 29),
+        ("type", 30),
+        ("unsafe", 31),
+        ("use", 32),
+        ("where", 33),
+        ("while", 34),
+        ("async", 35),
+        ("await", 36),
+        ("dyn", 37),
+        ("plumless", 38),
+        ("buckeroo!", 39),
+    ];
+
+    static MAP: Crc32Map<usize, 40> = Crc32Map::new(KEYWORDS);
+
+    #[test]
+    fn finds_every_key() {
+        for (key, value) in KEYWORDS {
+            assert_eq!(MAP.get(key), Some(&value), "{}", key);
+            assert_eq!(MAP.get_entry(key), Some(&(key, value)));
+        }
+        assert_eq!(MAP.len(), 40);
+        assert_eq!(MAP.entries(), &KEYWORDS[..]);
+    }
+
+    #[test]
+    fn rejects_missing_keys() {
+        for key in ["", "a", "Fn", "macro_rules", "yield", "plumles", "buckeroo"] {
+            assert!(!MAP.contains_key(key), "{}", key);
+        }
+    }
+
+    #[test]
+    fn lookup_at_compile_time() {
+        const VALUE: Option<&usize> = MAP_CONST.get("while");
+        const MAP_CONST: Crc32Map<usize, 40> = Crc32Map::new(KEYWORDS);
+        assert_eq!(VALUE, Some(&34));
+    }
+
+    #[test]
+    fn small_maps() {
+        static EMPTY: Crc32Map<(), 0> = Crc32Map::new([]);
+        static ONE: Crc32Map<u8, 1> = Crc32Map::new([("one", 1)]);
+        assert!(EMPTY.is_empty());
+        assert_eq!(EMPTY.get("one"), None);
+        assert_eq!(ONE.get("one"), Some(&1));
+        assert_eq!(ONE.get("two"), None);
+    }
+}
@@ -0,0 +1,100 @@
+//! Running a reflected 32-bit CRC backwards.
+//!
+//! Each step of the table-driven update is `crc' = (crc >> 8) ^ table[(crc ^ byte) & 0xff]`.
+//! The top byte of `crc'` comes from the table entry alone, and the top bytes of the 256
+//! entries are all distinct, so an inverse table mapping them back to the index undoes a step.
+//! This is enough to remove bytes from the end of a register, or to solve for the bytes that
+//! take one register value to another.
+
+use crate::Crc;
+
+/// computes the inverse of the top byte of `crc`'s table: `table[inverse[t]] >> 24 == t`
+pub(crate) const fn inverse_table(crc: &Crc<u32>) -> [u8; 256] {
+    assert!(
+        crc.algorithm().refin,
+        "inverse tables require a reflected algorithm"
+    );
+
+    let table = crc.table();
+    let mut inverse = [0u8; 256];
+    let mut seen = [false; 256];
+
+    let mut i = 0;
+    while i < 256 {
+        let top = (table[i] >> 24) as usize;
+        assert!(!seen[top], "table is not invertible");
+        seen[top] = true;
+        inverse[top] = i as u8;
+        i += 1;
+    }
+
+    inverse
+}
+
+/// undoes feeding `buf` through the register: `unupdate(crc.update(reg, buf), buf) == reg`
+pub(crate) const fn unupdate(crc: &Crc<u32>, inverse: &[u8; 256], reg: u32, buf: &[u8]) -> u32 {
+    let mut out = reg;
+    let mut i = buf.len();
+
+    while i > 0 {
+        i -= 1;
+        out = unstep(crc, inverse, out, buf[i]);
+    }
+
+    out
+}
+
+/// the four bytes that take the register from `from` to `to`
+pub(crate) const fn solve(crc: &Crc<u32>, inverse: &[u8; 256], from: u32, to: u32) -> [u8; 4] {
+    // feeding four bytes from `from` is the same as feeding four zero bytes from `from` xored
+    // with the bytes as a little-endian word, so run four zero bytes backwards from `to`
+    let mut reg = to;
+    let mut i = 0;
+    while i < 4 {
+        reg = unstep(crc, inverse, reg, 0);
+        i += 1;
+    }
+    (reg ^ from).to_le_bytes()
+}
+
+const fn unstep(crc: &Crc<u32>, inverse: &[u8; 256], reg: u32, byte: u8) -> u32 {
+    let index = inverse[(reg >> 24) as usize];
+    ((reg ^ crc.table()[index as usize]) << 8) | (index ^ byte) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog;
+    use rand::prelude::*;
+
+    const CRC: Crc<u32> = Crc::<u32>::new(catalog::CRC_32_ISCSI);
+    const INVERSE: [u8; 256] = inverse_table(&CRC);
+
+    #[test]
+    fn inverse_table_inverts_top_byte() {
+        for (top, &index) in INVERSE.iter().enumerate() {
+            assert_eq!(CRC.table()[index as usize] >> 24, top as u32);
+        }
+    }
+
+    #[test]
+    fn unupdate_undoes_update() {
+        let mut buf = [0u8; 100];
+        let mut rng = thread_rng();
+        rng.fill(&mut buf[..]);
+        let reg: u32 = rng.gen();
+
+        let forward = CRC.update(reg, &buf);
+        assert_eq!(unupdate(&CRC, &INVERSE, forward, &buf), reg);
+    }
+
+    #[test]
+    fn solve_reaches_target_register() {
+        let mut rng = thread_rng();
+        for _ in 0..100 {
+            let (from, to): (u32, u32) = rng.gen();
+            assert_eq!(CRC.update(from, &solve(&CRC, &INVERSE, from, to)), to);
+        }
+    }
+}
@@ -0,0 +1,310 @@
+//! Runtime checksums that pick the fastest available implementation.
+//!
+//! The `const fn`s in this crate are table driven, which is fine for compile-time data but slow
+//! on large dynamic inputs. The functions here detect CPU features at runtime and use SIMD when
+//! possible, falling back to the table-driven `const fn`s otherwise. They always return exactly
+//! what their `const fn` counterparts return, so checksums computed at compile time and at
+//! runtime can be compared freely.
+//!
+//! Requires the `std` feature.
+
+/// Runtime crc32 checksum, equal to [`crate::crc32`].
+///
+/// On x86_64 this uses carry-less multiplication (PCLMULQDQ) when the CPU supports it.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+///
+/// const BYTES: &[u8] = "The quick brown fox jumps over the lazy dog".as_bytes();
+/// const CKSUM: u32 = const_crc32::crc32(BYTES);
+///
+/// assert_eq!(const_crc32::runtime::crc32(BYTES), CKSUM);
+/// ```
+pub fn crc32(buf: &[u8]) -> u32 {
+    crc32_seed(buf, 0)
+}
+
+/// Runtime crc32 checksum starting from `seed`, equal to [`crate::crc32_seed`].
+pub fn crc32_seed(buf: &[u8], seed: u32) -> u32 {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if buf.len() >= pclmul::MIN_LEN
+            && std::is_x86_feature_detected!("pclmulqdq")
+            && std::is_x86_feature_detected!("sse4.1")
+        {
+            // SAFETY: the required target features were detected above
+            return unsafe { pclmul::crc32_seed(buf, seed) };
+        }
+    }
+
+    crate::crc32_seed(buf, seed)
+}
+
+/// Runtime crc32c checksum, equal to [`crate::crc32c`].
+///
+/// On x86_64 this uses the SSE4.2 `crc32` instruction when the CPU supports it.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd as const_crc32;
+///
+/// const BYTES: &[u8] = "The quick brown fox jumps over the lazy dog".as_bytes();
+/// const CKSUM: u32 = const_crc32::crc32c(BYTES);
+///
+/// assert_eq!(const_crc32::runtime::crc32c(BYTES), CKSUM);
+/// ```
+pub fn crc32c(buf: &[u8]) -> u32 {
+    crc32c_seed(buf, 0)
+}
+
+/// Runtime crc32c checksum starting from `seed`, equal to [`crate::crc32c_seed`].
+pub fn crc32c_seed(buf: &[u8], seed: u32) -> u32 {
+    #[cfg(target_arch = "x86_64")]
+    {
+        if std::is_x86_feature_detected!("sse4.2") {
+            // SAFETY: the required target feature was detected above
+            return unsafe { sse42::crc32c_seed(buf, seed) };
+        }
+    }
+
+    crate::crc32c_seed(buf, seed)
+}
+
+/// CRC-32/ISCSI using the SSE4.2 `crc32` instruction.
+///
+/// The instruction has a latency of three cycles but a throughput of one per cycle, so large
+/// inputs are split into three blocks that are checksummed in an interleaved fashion and then
+/// combined by multiplying by x^(8n) mod P(x), with `n` the length of the following blocks.
+#[cfg(target_arch = "x86_64")]
+mod sse42 {
+    use crate::gf2;
+    use core::arch::x86_64::*;
+
+    /// reflected CRC-32/ISCSI polynomial
+    const POLY: u32 = 0x82f63b78;
+
+    /// block lengths for the three-way interleaved loops
+    const LONG: usize = 8192;
+    const SHORT: usize = 256;
+
+    /// tables that append `LONG` and `SHORT` zero bytes to a register
+    const LONG_SHIFT: [[u32; 256]; 4] = gf2::shift_tables(POLY, LONG as u64);
+    const SHORT_SHIFT: [[u32; 256]; 4] = gf2::shift_tables(POLY, SHORT as u64);
+
+    /// # Safety
+    ///
+    /// The CPU must support `sse4.2`.
+    #[target_feature(enable = "sse4.2")]
+    pub(super) unsafe fn crc32c_seed(buf: &[u8], seed: u32) -> u32 {
+        let mut crc = !seed;
+        let mut buf = buf;
+
+        while buf.len() >= 3 * LONG {
+            crc = interleave3::<LONG>(crc, &buf[..3 * LONG], &LONG_SHIFT);
+            buf = &buf[3 * LONG..];
+        }
+        while buf.len() >= 3 * SHORT {
+            crc = interleave3::<SHORT>(crc, &buf[..3 * SHORT], &SHORT_SHIFT);
+            buf = &buf[3 * SHORT..];
+        }
+
+        let mut crc = crc as u64;
+        let mut words = buf.chunks_exact(8);
+        for word in &mut words {
+            crc = _mm_crc32_u64(crc, u64::from_le_bytes(word.try_into().unwrap()));
+        }
+        let mut crc = crc as u32;
+        for &byte in words.remainder() {
+            crc = _mm_crc32_u8(crc, byte);
+        }
+
+        !crc
+    }
+
+    /// feeds the `3 * N` bytes of `buf` through the register `crc`
+    #[inline]
+    #[target_feature(enable = "sse4.2")]
+    unsafe fn interleave3<const N: usize>(crc: u32, buf: &[u8], shift: &[[u32; 256]; 4]) -> u32 {
+        let (a, rest) = buf.split_at(N);
+        let (b, c) = rest.split_at(N);
+
+        let mut crc0 = crc as u64;
+        let mut crc1 = 0u64;
+        let mut crc2 = 0u64;
+        for ((a, b), c) in a
+            .chunks_exact(8)
+            .zip(b.chunks_exact(8))
+            .zip(c.chunks_exact(8))
+        {
+            crc0 = _mm_crc32_u64(crc0, u64::from_le_bytes(a.try_into().unwrap()));
+            crc1 = _mm_crc32_u64(crc1, u64::from_le_bytes(b.try_into().unwrap()));
+            crc2 = _mm_crc32_u64(crc2, u64::from_le_bytes(c.try_into().unwrap()));
+        }
+
+        let crc = gf2::shift(shift, crc0 as u32) ^ crc1 as u32;
+        gf2::shift(shift, crc) ^ crc2 as u32
+    }
+}
+
+/// CRC-32/ISO-HDLC by folding with carry-less multiplication, following Intel's "Fast CRC
+/// Computation for Generic Polynomials Using PCLMULQDQ Instruction" (Gopal et al., 2009).
+#[cfg(target_arch = "x86_64")]
+mod pclmul {
+    use core::arch::x86_64::*;
+
+    /// inputs shorter than this are left to the table-driven implementation
+    pub(super) const MIN_LEN: usize = 128;
+
+    // folding constants for the reflected polynomial, x^n mod P(x) bit-reflected and shifted
+    // left by one, as given in the paper
+    const K1: i64 = 0x154442bd4; // x^(4*128+32) mod P(x)
+    const K2: i64 = 0x1c6e41596; // x^(4*128-32) mod P(x)
+    const K3: i64 = 0x1751997d0; // x^(128+32) mod P(x)
+    const K4: i64 = 0x0ccaa009e; // x^(128-32) mod P(x)
+    const K5: i64 = 0x163cd6124; // x^64 mod P(x)
+    const P_X: i64 = 0x1db710641; // P(x), reflected
+    const U_PRIME: i64 = 0x1f7011641; // floor(x^64 / P(x)), reflected
+
+    /// # Safety
+    ///
+    /// The CPU must support `pclmulqdq` and `sse4.1`, and `buf` must hold at least `MIN_LEN`
+    /// bytes.
+    #[target_feature(enable = "pclmulqdq,sse2,sse4.1")]
+    pub(super) unsafe fn crc32_seed(buf: &[u8], seed: u32) -> u32 {
+        debug_assert!(buf.len() >= MIN_LEN);
+        let mut buf = buf;
+
+        let mut x3 = load(&mut buf);
+        let mut x2 = load(&mut buf);
+        let mut x1 = load(&mut buf);
+        let mut x0 = load(&mut buf);
+
+        // the register starts as `!seed`, just as in `crc32_seed`
+        x3 = _mm_xor_si128(x3, _mm_cvtsi32_si128(!seed as i32));
+
+        // fold 512 bits at a time into the four accumulators
+        let k1k2 = _mm_set_epi64x(K2, K1);
+        while buf.len() >= 64 {
+            x3 = fold(x3, load(&mut buf), k1k2);
+            x2 = fold(x2, load(&mut buf), k1k2);
+            x1 = fold(x1, load(&mut buf), k1k2);
+            x0 = fold(x0, load(&mut buf), k1k2);
+        }
+
+        // fold the accumulators, then any remaining whole 128-bit blocks, into one
+        let k3k4 = _mm_set_epi64x(K4, K3);
+        let mut x = fold(x3, x2, k3k4);
+        x = fold(x, x1, k3k4);
+        x = fold(x, x0, k3k4);
+        while buf.len() >= 16 {
+            x = fold(x, load(&mut buf), k3k4);
+        }
+
+        // reduce 128 bits to 96, then to 64
+        let low32 = _mm_set_epi32(0, 0, 0, !0);
+        let x = _mm_xor_si128(_mm_clmulepi64_si128(x, k3k4, 0x10), _mm_srli_si128(x, 8));
+        let x = _mm_xor_si128(
+            _mm_clmulepi64_si128(_mm_and_si128(x, low32), _mm_set_epi64x(0, K5), 0x00),
+            _mm_srli_si128(x, 4),
+        );
+
+        // Barrett reduction from 64 bits to 32, bit-reflected variant
+        let pu = _mm_set_epi64x(U_PRIME, P_X);
+        let t1 = _mm_clmulepi64_si128(_mm_and_si128(x, low32), pu, 0x10);
+        let t2 = _mm_clmulepi64_si128(_mm_and_si128(t1, low32), pu, 0x00);
+        let crc = !(_mm_extract_epi32(_mm_xor_si128(x, t2), 1) as u32);
+
+        // less than 16 bytes remain
+        crate::crc32_seed(buf, crc)
+    }
+
+    #[inline]
+    #[target_feature(enable = "pclmulqdq,sse2")]
+    unsafe fn fold(a: __m128i, b: __m128i, keys: __m128i) -> __m128i {
+        let lo = _mm_clmulepi64_si128(a, keys, 0x00);
+        let hi = _mm_clmulepi64_si128(a, keys, 0x11);
+        _mm_xor_si128(_mm_xor_si128(b, lo), hi)
+    }
+
+    #[inline]
+    #[target_feature(enable = "sse2")]
+    unsafe fn load(buf: &mut &[u8]) -> __m128i {
+        let (head, tail) = buf.split_at(16);
+        *buf = tail;
+        _mm_loadu_si128(head.as_ptr() as *const __m128i)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::*;
+
+    #[test]
+    fn check_random_inputs_against_const_fn() {
+        const N_ITER: usize = 100;
+        const BUFSIZE: usize = 4096;
+
+        let mut buf = [0u8; BUFSIZE];
+        let mut rng = thread_rng();
+
+        for _ in 0..N_ITER {
+            rng.fill(&mut buf[..]);
+            let start = rng.gen_range(0..64);
+            let end = rng.gen_range(start..BUFSIZE);
+            let seed = rng.gen();
+            let data = &buf[start..end];
+            assert_eq!(crc32_seed(data, seed), crate::crc32_seed(data, seed));
+            assert_eq!(crc32(data), crc32fast::hash(data));
+        }
+    }
+
+    #[test]
+    fn check_random_crc32c_inputs_against_const_fn() {
+        const N_ITER: usize = 100;
+        const BUFSIZE: usize = 4096;
+
+        let mut buf = [0u8; BUFSIZE];
+        let mut rng = thread_rng();
+
+        for _ in 0..N_ITER {
+            rng.fill(&mut buf[..]);
+            let start = rng.gen_range(0..64);
+            let end = rng.gen_range(start..BUFSIZE);
+            let seed = rng.gen();
+            let data = &buf[start..end];
+            assert_eq!(crc32c_seed(data, seed), crate::crc32c_seed(data, seed));
+        }
+    }
+
+    #[test]
+    fn check_large_crc32c_inputs_against_const_fn() {
+        let mut buf = std::vec![0u8; 3 * 8192 * 2 + 3 * 256 + 123];
+        let mut rng = thread_rng();
+        rng.fill(&mut buf[..]);
+
+        for len in [3 * 8192 - 1, 3 * 8192, 3 * 8192 + 3 * 256 + 9, buf.len()] {
+            assert_eq!(crc32c(&buf[..len]), crate::crc32c(&buf[..len]));
+        }
+    }
+
+    #[test]
+    fn check_every_length_around_block_boundaries() {
+        let mut buf = [0u8; 512];
+        let mut rng = thread_rng();
+        rng.fill(&mut buf[..]);
+
+        for len in 0..buf.len() {
+            assert_eq!(crc32(&buf[..len]), crate::crc32(&buf[..len]), "len {}", len);
+            assert_eq!(
+                crc32c(&buf[..len]),
+                crate::crc32c(&buf[..len]),
+                "len {}",
+                len
+            );
+        }
+    }
+}
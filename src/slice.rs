@@ -0,0 +1,123 @@
+//! Slice-by-8 and slice-by-16 table lookups for reflected 32-bit CRCs.
+//!
+//! Byte-at-a-time processing spends one loop iteration per input byte, which is what runs
+//! into the const evaluation limit on large inputs. Slicing consumes 8 or 16 bytes per
+//! iteration using extra tables derived from the algorithm's base table, at the cost of 8KB
+//! or 16KB of tables.
+
+use crate::Crc;
+
+/// computes the slicing tables for a reflected 32-bit `crc`. `tables[0]` is the base table and
+/// `tables[k][i]` is the register contribution of byte `i` followed by `k` zero bytes
+pub(crate) const fn tables<const N: usize>(crc: &Crc<u32>) -> [[u32; 256]; N] {
+    assert!(
+        crc.algorithm().refin,
+        "slicing tables require a reflected algorithm"
+    );
+
+    let base = crc.table();
+    let mut tables = [[0u32; 256]; N];
+    tables[0] = *base;
+
+    let mut k = 1;
+    while k < N {
+        let mut i = 0;
+        while i < 256 {
+            let prev = tables[k - 1][i];
+            tables[k][i] = (prev >> 8) ^ base[(prev & 0xff) as usize];
+            i += 1;
+        }
+        k += 1;
+    }
+
+    tables
+}
+
+/// feeds `buf` through the register `crc`, 8 bytes per iteration
+pub(crate) const fn update_slice8(tables: &[[u32; 256]; 8], crc: u32, buf: &[u8]) -> u32 {
+    let mut out = crc;
+    let mut rest = buf;
+
+    while let [b0, b1, b2, b3, b4, b5, b6, b7, tail @ ..] = rest {
+        let lo = out ^ u32::from_le_bytes([*b0, *b1, *b2, *b3]);
+        out = tables[7][(lo & 0xff) as usize]
+            ^ tables[6][((lo >> 8) & 0xff) as usize]
+            ^ tables[5][((lo >> 16) & 0xff) as usize]
+            ^ tables[4][(lo >> 24) as usize]
+            ^ tables[3][*b4 as usize]
+            ^ tables[2][*b5 as usize]
+            ^ tables[1][*b6 as usize]
+            ^ tables[0][*b7 as usize];
+        rest = tail;
+    }
+
+    update_bytes(&tables[0], out, rest)
+}
+
+/// feeds `buf` through the register `crc`, 16 bytes per iteration
+pub(crate) const fn update_slice16(tables: &[[u32; 256]; 16], crc: u32, buf: &[u8]) -> u32 {
+    let mut out = crc;
+    let mut rest = buf;
+
+    while let [b0, b1, b2, b3, b4, b5, b6, b7, b8, b9, b10, b11, b12, b13, b14, b15, tail @ ..] =
+        rest
+    {
+        let lo = out ^ u32::from_le_bytes([*b0, *b1, *b2, *b3]);
+        out = tables[15][(lo & 0xff) as usize]
+            ^ tables[14][((lo >> 8) & 0xff) as usize]
+            ^ tables[13][((lo >> 16) & 0xff) as usize]
+            ^ tables[12][(lo >> 24) as usize]
+            ^ tables[11][*b4 as usize]
+            ^ tables[10][*b5 as usize]
+            ^ tables[9][*b6 as usize]
+            ^ tables[8][*b7 as usize]
+            ^ tables[7][*b8 as usize]
+            ^ tables[6][*b9 as usize]
+            ^ tables[5][*b10 as usize]
+            ^ tables[4][*b11 as usize]
+            ^ tables[3][*b12 as usize]
+            ^ tables[2][*b13 as usize]
+            ^ tables[1][*b14 as usize]
+            ^ tables[0][*b15 as usize];
+        rest = tail;
+    }
+
+    update_bytes(&tables[0], out, rest)
+}
+
+/// byte-at-a-time processing of whatever is left over after the sliced loop
+const fn update_bytes(table: &[u32; 256], crc: u32, buf: &[u8]) -> u32 {
+    let mut out = crc;
+    let mut i = 0usize;
+    while i < buf.len() {
+        out = (out >> 8) ^ table[((out & 0xff) ^ (buf[i] as u32)) as usize];
+        i += 1;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::catalog;
+    use rand::prelude::*;
+
+    const CRC: Crc<u32> = Crc::<u32>::new(catalog::CRC_32_ISCSI);
+    const TABLES: [[u32; 256]; 16] = tables::<16>(&CRC);
+
+    #[test]
+    fn slice8_and_slice16_match_byte_at_a_time() {
+        let mut buf = [0u8; 1024];
+        let mut rng = thread_rng();
+        rng.fill(&mut buf[..]);
+
+        let tables8: [[u32; 256]; 8] = tables::<8>(&CRC);
+        assert_eq!(tables8[..], TABLES[..8]);
+
+        for len in [0, 1, 7, 8, 9, 15, 16, 17, 31, 33, 1000, 1024] {
+            let expected = CRC.update(CRC.init(), &buf[..len]);
+            assert_eq!(update_slice8(&tables8, CRC.init(), &buf[..len]), expected);
+            assert_eq!(update_slice16(&TABLES, CRC.init(), &buf[..len]), expected);
+        }
+    }
+}
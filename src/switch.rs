@@ -0,0 +1,150 @@
+//! Dispatching on strings by their crc32, with collisions rejected at compile time.
+//!
+//! See [`crc32_switch!`](crate::crc32_switch).
+
+use crate::crc32;
+
+/// Find two keys with the same crc32, returning their indices.
+///
+/// Identical keys count as a collision.
+pub const fn find_crc32_collision(keys: &[&str]) -> Option<(usize, usize)> {
+    let mut i = 0;
+    while i < keys.len() {
+        let hash = crc32(keys[i].as_bytes());
+        let mut j = i + 1;
+        while j < keys.len() {
+            if crc32(keys[j].as_bytes()) == hash {
+                return Some((i, j));
+            }
+            j += 1;
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Panics if any two of `keys` have the same crc32. Evaluated in a `const`, this turns a
+/// collision into a compile error.
+pub const fn assert_distinct_crc32(keys: &[&str]) {
+    const PREFIX: &[u8] = b"crc32 of key collides with an earlier key: ";
+    const MAX_LEN: usize = 256;
+
+    let Some((_, j)) = find_crc32_collision(keys) else {
+        return;
+    };
+
+    // const panics can only format a single `&str`, so build the message by hand
+    let key = keys[j].as_bytes();
+    if PREFIX.len() + key.len() > MAX_LEN {
+        panic!("crc32 of a key collides with an earlier key");
+    }
+    let mut msg = [0u8; MAX_LEN];
+    let mut i = 0;
+    while i < PREFIX.len() {
+        msg[i] = PREFIX[i];
+        i += 1;
+    }
+    while i < PREFIX.len() + key.len() {
+        msg[i] = key[i - PREFIX.len()];
+        i += 1;
+    }
+    match core::str::from_utf8(msg.split_at(i).0) {
+        Ok(msg) => panic!("{}", msg),
+        Err(_) => panic!("crc32 of a key collides with an earlier key"),
+    }
+}
+
+/// Match a `&str` against string literals by comparing crc32 checksums.
+///
+/// The checksum of every key is computed at compile time, and compilation fails if two keys
+/// have the same crc32 (or are identical). At runtime the input is hashed once, and a
+/// matching checksum is confirmed by comparing the strings, so inputs that merely collide
+/// with a key fall through to the `_` arm.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd::crc32_switch;
+///
+/// fn opcode(name: &str) -> u8 {
+///     crc32_switch!(name => {
+///         "start" => 1,
+///         "stop" => 2,
+///         "status" => 3,
+///         _ => 0,
+///     })
+/// }
+///
+/// assert_eq!(opcode("stop"), 2);
+/// assert_eq!(opcode("restart"), 0);
+/// ```
+///
+/// "plumless" and "buckeroo" have the same crc32, so this fails to compile with "crc32 of key
+/// collides with an earlier key: buckeroo":
+///
+/// ```compile_fail
+/// use const_crc32_nostd::crc32_switch;
+///
+/// fn kind(name: &str) -> u8 {
+///     crc32_switch!(name => {
+///         "plumless" => 1,
+///         "buckeroo" => 2,
+///         _ => 0,
+///     })
+/// }
+/// ```
+#[macro_export]
+macro_rules! crc32_switch {
+    ($input:expr => { $($key:literal => $arm:expr),+ , _ => $default:expr $(,)? }) => {{
+        const _: () = $crate::switch::assert_distinct_crc32(&[$($key),+]);
+        let input: &str = $input;
+        match $crate::crc32(input.as_bytes()) {
+            $(
+                hash if hash == const { $crate::crc32($key.as_bytes()) } && input == $key => $arm,
+            )+
+            _ => $default,
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn command(name: &str) -> &'static str {
+        crate::crc32_switch!(name => {
+            "get" => "GET",
+            "set" => "SET",
+            "del" => "DEL",
+            "plumless" => "PLUMLESS",
+            _ => "UNKNOWN",
+        })
+    }
+
+    #[test]
+    fn dispatches_on_keys() {
+        assert_eq!(command("get"), "GET");
+        assert_eq!(command("set"), "SET");
+        assert_eq!(command("del"), "DEL");
+        assert_eq!(command("plumless"), "PLUMLESS");
+        assert_eq!(command("put"), "UNKNOWN");
+        assert_eq!(command(""), "UNKNOWN");
+    }
+
+    #[test]
+    fn colliding_input_is_not_matched() {
+        assert_eq!(crc32(b"plumless"), crc32(b"buckeroo"));
+        assert_eq!(command("buckeroo"), "UNKNOWN");
+    }
+
+    #[test]
+    fn finds_collisions() {
+        assert_eq!(find_crc32_collision(&["get", "set", "del"]), None);
+        assert_eq!(
+            find_crc32_collision(&["get", "plumless", "set", "buckeroo"]),
+            Some((1, 3))
+        );
+        assert_eq!(find_crc32_collision(&["get", "set", "get"]), Some((0, 2)));
+        assert_eq!(find_crc32_collision(&[]), None);
+    }
+}
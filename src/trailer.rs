@@ -0,0 +1,172 @@
+//! Frames that carry their crc32 as a 4-byte trailer.
+//!
+//! Appending the little-endian crc32 of a payload gives a frame whose own crc32 is always
+//! [`CRC32_RESIDUE`], whatever the payload. Checking a little-endian frame is therefore a
+//! single pass over the whole frame with no need to locate the trailer first.
+
+use crate::{catalog, crc32, verify_frame_be};
+
+/// crc32 of any payload followed by its little-endian crc32.
+pub const CRC32_RESIDUE: u32 = catalog::CRC_32_ISO_HDLC.residue ^ catalog::CRC_32_ISO_HDLC.xorout;
+
+/// The crc32 of `payload` as a little-endian trailer.
+pub const fn trailer_le(payload: &[u8]) -> [u8; 4] {
+    crc32(payload).to_le_bytes()
+}
+
+/// The crc32 of `payload` as a big-endian trailer.
+pub const fn trailer_be(payload: &[u8]) -> [u8; 4] {
+    crc32(payload).to_be_bytes()
+}
+
+/// Overwrite the last 4 bytes of `frame` with the little-endian crc32 of the bytes before them.
+///
+/// # Panics
+///
+/// Panics if `frame` is shorter than 4 bytes.
+pub fn write_trailer_le(frame: &mut [u8]) {
+    let (payload, trailer) = frame.split_at_mut(frame.len() - 4);
+    trailer.copy_from_slice(&trailer_le(payload));
+}
+
+/// Overwrite the last 4 bytes of `frame` with the big-endian crc32 of the bytes before them.
+///
+/// # Panics
+///
+/// Panics if `frame` is shorter than 4 bytes.
+pub fn write_trailer_be(frame: &mut [u8]) {
+    let (payload, trailer) = frame.split_at_mut(frame.len() - 4);
+    trailer.copy_from_slice(&trailer_be(payload));
+}
+
+/// [`write_trailer_le`] by value, for building frames in `const`s and `static`s. The last 4
+/// bytes of `frame` are placeholders for the trailer.
+///
+/// # Panics
+///
+/// Panics if `N` is less than 4.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd::trailer::{check_frame_le, seal_le};
+///
+/// const PING: [u8; 8] = seal_le([0x01, 0x00, 0x00, 0x2a, 0, 0, 0, 0]);
+///
+/// assert_eq!(check_frame_le(&PING), Some(&[0x01, 0x00, 0x00, 0x2a][..]));
+/// ```
+pub const fn seal_le<const N: usize>(frame: [u8; N]) -> [u8; N] {
+    assert!(N >= 4);
+    let trailer = trailer_le(frame.split_at(N - 4).0);
+    let mut frame = frame;
+    let mut i = 0;
+    while i < 4 {
+        frame[N - 4 + i] = trailer[i];
+        i += 1;
+    }
+    frame
+}
+
+/// [`write_trailer_be`] by value, for building frames in `const`s and `static`s. The last 4
+/// bytes of `frame` are placeholders for the trailer.
+///
+/// # Panics
+///
+/// Panics if `N` is less than 4.
+pub const fn seal_be<const N: usize>(frame: [u8; N]) -> [u8; N] {
+    assert!(N >= 4);
+    let trailer = trailer_be(frame.split_at(N - 4).0);
+    let mut frame = frame;
+    let mut i = 0;
+    while i < 4 {
+        frame[N - 4 + i] = trailer[i];
+        i += 1;
+    }
+    frame
+}
+
+/// Check a frame ending in the little-endian crc32 of its payload, returning the payload if
+/// the checksum matches.
+///
+/// This is a single crc32 pass over the whole frame, compared against [`CRC32_RESIDUE`]. Use
+/// [`verify_frame_le`](crate::verify_frame_le) to find out both checksums on a mismatch.
+pub const fn check_frame_le(frame: &[u8]) -> Option<&[u8]> {
+    if frame.len() < 4 || crc32(frame) != CRC32_RESIDUE {
+        return None;
+    }
+    Some(frame.split_at(frame.len() - 4).0)
+}
+
+/// Check a frame ending in the big-endian crc32 of its payload, returning the payload if the
+/// checksum matches.
+///
+/// The residue only applies to trailers in the CRC's own bit order, so this checksums the
+/// payload and compares it with the trailer. Use [`verify_frame_be`] to find out both
+/// checksums on a mismatch.
+pub const fn check_frame_be(frame: &[u8]) -> Option<&[u8]> {
+    if frame.len() < 4 {
+        return None;
+    }
+    match verify_frame_be(frame) {
+        Ok(payload) => Some(payload),
+        Err(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::prelude::*;
+
+    #[test]
+    fn residue_value() {
+        assert_eq!(CRC32_RESIDUE, 0x2144df1c);
+    }
+
+    #[test]
+    fn write_and_check_random_frames() {
+        let mut buf = [0u8; 260];
+        let mut rng = thread_rng();
+
+        for len in [4, 5, 8, 100, 260] {
+            rng.fill(&mut buf[..]);
+            let frame = &mut buf[..len];
+            let cksum = crc32(&frame[..len - 4]);
+
+            write_trailer_le(frame);
+            assert_eq!(frame[len - 4..], cksum.to_le_bytes());
+            assert_eq!(crc32(frame), CRC32_RESIDUE);
+            assert_eq!(check_frame_le(frame).map(<[u8]>::len), Some(len - 4));
+
+            write_trailer_be(frame);
+            assert_eq!(frame[len - 4..], cksum.to_be_bytes());
+            assert_eq!(check_frame_be(frame).map(<[u8]>::len), Some(len - 4));
+
+            frame[0] ^= 1;
+            assert_eq!(check_frame_be(frame), None);
+            write_trailer_le(frame);
+            frame[len - 1] ^= 0x80;
+            assert_eq!(check_frame_le(frame), None);
+        }
+    }
+
+    #[test]
+    fn short_frames_are_rejected() {
+        assert_eq!(check_frame_le(&[]), None);
+        assert_eq!(check_frame_le(&[0x1c, 0xdf, 0x44]), None);
+        assert_eq!(check_frame_be(&[0, 0, 0]), None);
+        // the empty payload is fine
+        assert_eq!(check_frame_le(&trailer_le(&[])), Some(&[][..]));
+        assert_eq!(check_frame_be(&trailer_be(&[])), Some(&[][..]));
+    }
+
+    #[test]
+    fn seal_static_frames() {
+        const LE: [u8; 7] = seal_le(*b"abc\0\0\0\0");
+        const BE: [u8; 7] = seal_be(*b"abc\0\0\0\0");
+        assert_eq!(LE[3..], crc32(b"abc").to_le_bytes());
+        assert_eq!(BE[3..], crc32(b"abc").to_be_bytes());
+        const PAYLOAD: Option<&[u8]> = check_frame_le(&LE);
+        assert_eq!(PAYLOAD, Some(&b"abc"[..]));
+    }
+}
@@ -0,0 +1,188 @@
+//! Checking data against an expected crc32.
+
+use core::fmt;
+
+use crate::crc32;
+
+/// The crc32 of some data did not match the expected value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ChecksumMismatch {
+    /// the checksum the data was supposed to have
+    pub expected: u32,
+    /// the checksum the data actually has
+    pub actual: u32,
+}
+
+impl fmt::Display for ChecksumMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "crc32 mismatch: expected {:08x}, found {:08x}",
+            self.expected, self.actual
+        )
+    }
+}
+
+impl core::error::Error for ChecksumMismatch {}
+
+/// Check that the crc32 of `data` is `expected`.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd::{verify, ChecksumMismatch};
+///
+/// assert_eq!(verify(b"123456789", 0xcbf43926), Ok(()));
+/// assert_eq!(
+///     verify(b"123456789", 0xbaaaaaad),
+///     Err(ChecksumMismatch { expected: 0xbaaaaaad, actual: 0xcbf43926 }),
+/// );
+/// ```
+pub const fn verify(data: &[u8], expected: u32) -> Result<(), ChecksumMismatch> {
+    let actual = crc32(data);
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(ChecksumMismatch { expected, actual })
+    }
+}
+
+/// Check `data` against a crc32 stored as 4 little-endian bytes, e.g. read from a trailer.
+pub const fn verify_le(data: &[u8], stored: [u8; 4]) -> Result<(), ChecksumMismatch> {
+    verify(data, u32::from_le_bytes(stored))
+}
+
+/// Check `data` against a crc32 stored as 4 big-endian bytes, e.g. read from a trailer.
+pub const fn verify_be(data: &[u8], stored: [u8; 4]) -> Result<(), ChecksumMismatch> {
+    verify(data, u32::from_be_bytes(stored))
+}
+
+/// Check a frame ending in the little-endian crc32 of its payload, returning the payload.
+///
+/// # Panics
+///
+/// Panics if `frame` is shorter than 4 bytes.
+///
+/// # Examples
+///
+/// ```
+/// use const_crc32_nostd::{verify_frame_le, ChecksumMismatch};
+///
+/// const FRAME: &[u8] = b"123456789\x26\x39\xf4\xcb";
+/// const PAYLOAD: Result<&[u8], ChecksumMismatch> = verify_frame_le(FRAME);
+/// assert_eq!(PAYLOAD, Ok(&b"123456789"[..]));
+///
+/// assert_eq!(
+///     verify_frame_le(b"12345678\x26\x39\xf4\xcb"),
+///     Err(ChecksumMismatch { expected: 0xcbf43926, actual: 0x9ae0daaf }),
+/// );
+/// ```
+pub const fn verify_frame_le(frame: &[u8]) -> Result<&[u8], ChecksumMismatch> {
+    let (payload, stored) = split_trailer(frame);
+    match verify_le(payload, stored) {
+        Ok(()) => Ok(payload),
+        Err(err) => Err(err),
+    }
+}
+
+/// Check a frame ending in the big-endian crc32 of its payload, returning the payload.
+///
+/// # Panics
+///
+/// Panics if `frame` is shorter than 4 bytes.
+pub const fn verify_frame_be(frame: &[u8]) -> Result<&[u8], ChecksumMismatch> {
+    let (payload, stored) = split_trailer(frame);
+    match verify_be(payload, stored) {
+        Ok(()) => Ok(payload),
+        Err(err) => Err(err),
+    }
+}
+
+/// splits `frame` into its payload and 4-byte trailer
+const fn split_trailer(frame: &[u8]) -> (&[u8], [u8; 4]) {
+    assert!(frame.len() >= 4, "frame is shorter than its 4-byte trailer");
+    let (payload, trailer) = frame.split_at(frame.len() - 4);
+    (payload, [trailer[0], trailer[1], trailer[2], trailer[3]])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BYTES: &[u8] = "The quick brown fox jumps over the lazy dog".as_bytes();
+
+    #[test]
+    fn verify_reports_both_values() {
+        assert_eq!(verify(BYTES, 0x414fa339), Ok(()));
+        let err = verify(&BYTES[1..], 0x414fa339).unwrap_err();
+        assert_eq!(err.expected, 0x414fa339);
+        assert_eq!(err.actual, crc32(&BYTES[1..]));
+    }
+
+    #[test]
+    fn verify_stored_byte_orders() {
+        assert_eq!(verify_le(BYTES, [0x39, 0xa3, 0x4f, 0x41]), Ok(()));
+        assert_eq!(verify_be(BYTES, [0x41, 0x4f, 0xa3, 0x39]), Ok(()));
+        assert_eq!(
+            verify_le(BYTES, [0x41, 0x4f, 0xa3, 0x39]),
+            Err(ChecksumMismatch {
+                expected: 0x39a34f41,
+                actual: 0x414fa339
+            })
+        );
+    }
+
+    #[test]
+    fn verify_frames() {
+        let mut frame = [0u8; 47];
+        frame[..43].copy_from_slice(BYTES);
+
+        frame[43..].copy_from_slice(&0x414fa339_u32.to_le_bytes());
+        assert_eq!(verify_frame_le(&frame), Ok(BYTES));
+        assert_eq!(
+            verify_frame_be(&frame),
+            Err(ChecksumMismatch {
+                expected: 0x39a34f41,
+                actual: 0x414fa339
+            })
+        );
+
+        frame[43..].copy_from_slice(&0x414fa339_u32.to_be_bytes());
+        assert_eq!(verify_frame_be(&frame), Ok(BYTES));
+        // a bare trailer is a frame with an empty payload
+        assert_eq!(
+            verify_frame_le(&frame[43..]),
+            Err(ChecksumMismatch {
+                expected: 0x39a34f41,
+                actual: 0
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn verify_short_frame_panics() {
+        let _ = verify_frame_le(b"abc");
+    }
+
+    #[test]
+    fn verify_at_compile_time() {
+        const RESULT: Result<(), ChecksumMismatch> = verify(BYTES, 0x414fa339);
+        assert!(RESULT.is_ok());
+    }
+
+    #[test]
+    fn display() {
+        extern crate std;
+        use std::string::ToString;
+
+        let err = ChecksumMismatch {
+            expected: 0xbaaaaaad,
+            actual: 0x2bad,
+        };
+        assert_eq!(
+            err.to_string(),
+            "crc32 mismatch: expected baaaaaad, found 00002bad"
+        );
+    }
+}